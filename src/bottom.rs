@@ -0,0 +1,141 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use crate::fishing_view::*;
+use crate::physics::*;
+use crate::tide::*;
+
+// The depth maps cover the whole fishing view
+const VIEW_WIDTH: f32 = 1280.;
+const VIEW_HEIGHT: f32 = 720.;
+
+// Used when a spot has no depth map, or it fails to load
+const POND_DEFAULT_DEPTH: f32 = 30.;
+const OCEAN_DEFAULT_DEPTH: f32 = 200.;
+
+// Grid of water depths in metres at mean water level. The first row is the
+// top of the fishing view, furthest from the shore.
+pub struct DepthMap {
+    cols: usize,
+    rows: usize,
+    depths: Vec<f32>,
+}
+
+impl DepthMap {
+    pub fn flat(depth: f32) -> Self {
+        Self {
+            cols: 1,
+            rows: 1,
+            depths: vec![depth],
+        }
+    }
+
+    // Reads a depth file, one row of comma separated depths per line. Every
+    // row needs the same number of columns, lines starting with # are comments.
+    pub fn load(filename: &str) -> Option<Self> {
+        let file = File::open(filename).ok()?;
+        let mut depths = Vec::new();
+        let mut cols = 0;
+        let mut rows = 0;
+
+        for line in BufReader::new(file).lines() {
+            let line = line.ok()?;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let row: Vec<f32> = line
+                .split(",")
+                .map(|value| value.trim().parse().ok())
+                .collect::<Option<Vec<f32>>>()?;
+
+            if rows == 0 {
+                cols = row.len();
+            } else if row.len() != cols {
+                println!("Uneven row in {}: {}", filename, line);
+                return None;
+            }
+
+            depths.extend(row);
+            rows += 1;
+        }
+
+        if rows == 0 || cols == 0 {
+            return None;
+        }
+
+        Some(Self { cols, rows, depths })
+    }
+
+    fn get(&self, col: usize, row: usize) -> f32 {
+        self.depths[row.min(self.rows - 1) * self.cols + col.min(self.cols - 1)]
+    }
+
+    // Blends between the four nearest grid points, position is relative to the
+    // centre of the fishing view
+    pub fn depth_at(&self, relative: Vec2) -> f32 {
+        let x = ((relative.x / VIEW_WIDTH + 0.5) * (self.cols - 1) as f32).clamp(0., (self.cols - 1) as f32);
+        let y = ((0.5 - relative.y / VIEW_HEIGHT) * (self.rows - 1) as f32).clamp(0., (self.rows - 1) as f32);
+
+        let (col, row) = (x.floor() as usize, y.floor() as usize);
+        let (tx, ty) = (x.fract(), y.fract());
+
+        let top = self.get(col, row) * (1. - tx) + self.get(col + 1, row) * tx;
+        let bottom = self.get(col, row + 1) * (1. - tx) + self.get(col + 1, row + 1) * tx;
+        top * (1. - ty) + bottom * ty
+    }
+}
+
+// Shape of the lake and sea floor at each fishing spot
+#[derive(Resource)]
+pub struct Bottom {
+    maps: HashMap<FishingLocal, DepthMap>,
+}
+
+impl Default for Bottom {
+    fn default() -> Self {
+        let mut maps = HashMap::new();
+
+        for (local, filename, fallback) in [
+            (FishingLocal::Pond1, "assets/fishing_view/pond1.depth", POND_DEFAULT_DEPTH),
+            (FishingLocal::Pond2, "assets/fishing_view/pond2.depth", POND_DEFAULT_DEPTH),
+            (FishingLocal::Ocean, "assets/fishing_view/ocean.depth", OCEAN_DEFAULT_DEPTH),
+        ] {
+            let map = DepthMap::load(filename).unwrap_or_else(|| {
+                println!("Couldn't load {}, using a flat bottom", filename);
+                DepthMap::flat(fallback)
+            });
+            maps.insert(local, map);
+        }
+
+        Self { maps }
+    }
+}
+
+impl Bottom {
+    // Metres of water at mean level over a world position
+    pub fn depth_at(&self, local: &FishingLocal, position: Vec3) -> f32 {
+        self.maps
+            .get(local)
+            .map_or(POND_DEFAULT_DEPTH, |map| map.depth_at(position.truncate() - FISHING_ROOM_CENTER))
+    }
+
+    // Physics z of the bottom, the tide adds water on top of it
+    pub fn floor_z(&self, local: &FishingLocal, position: Vec3, tide: &Tide) -> f32 {
+        -(self.depth_at(local, position) + tide.depth_at(local)) * DEPTH_UNITS_PER_METER
+    }
+
+    // Somewhere a species can swim, the water has to reach down to its depth band
+    pub fn can_live(&self, local: &FishingLocal, position: Vec3, depth: (i32, i32)) -> bool {
+        self.depth_at(local, position) >= depth.0 as f32
+    }
+
+    // Fish stranded in the shallows can still head for deeper water
+    pub fn can_swim(&self, local: &FishingLocal, from: Vec3, to: Vec3, depth: (i32, i32)) -> bool {
+        self.can_live(local, to, depth) || self.depth_at(local, to) > self.depth_at(local, from)
+    }
+}
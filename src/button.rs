@@ -77,19 +77,27 @@ pub fn fishing_button_system(
                 if input.just_pressed(KeyCode::Escape) && state.eq(&CurrentInterface::Fishing) {
                     println!("Exiting fishing mode");
                     // *color = NORMAL_BUTTON.into();  
-                    start_fishing_animation.active = false;
-                    start_fishing_animation.button_control_active = true;
-                    *visibility = Visibility::Visible;  
-    
-                    
-                    next_state.set(CurrentInterface::Overworld);
-                    println!("Switching to overworld mode");
+                    exit_fishing(&mut start_fishing_animation, &mut visibility, &mut next_state);
                 } 
             }
         }
     }
 }
 
+// Back to the overworld with the fishing button showing again
+pub fn exit_fishing(
+    start_fishing_animation: &mut StartFishingAnimation,
+    visibility: &mut Visibility,
+    next_state: &mut NextState<CurrentInterface>,
+) {
+    start_fishing_animation.active = false;
+    start_fishing_animation.button_control_active = true;
+    *visibility = Visibility::Visible;
+
+    next_state.set(CurrentInterface::Overworld);
+    println!("Switching to overworld mode");
+}
+
 pub fn spawn_fishing_button(commands: &mut Commands, asset_server: &Res<AssetServer>) {
     commands
         .spawn((
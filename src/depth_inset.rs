@@ -0,0 +1,234 @@
+use bevy::prelude::*;
+
+use crate::bottom::*;
+use crate::fish::*;
+use crate::fishing_view::*;
+use crate::interface::*;
+use crate::physics::*;
+use crate::species::*;
+use crate::tide::*;
+
+// Side view of the water under the line, shore on the left and open water on
+// the right, drawn in the top right corner while fishing
+const INSET_WIDTH: f32 = 280.;
+const INSET_HEIGHT: f32 = 180.;
+// Room left above the water for the depth readout
+const INSET_SURFACE: f32 = 30.;
+const INSET_COLUMNS: usize = 28;
+const MAX_SONAR_FISH: usize = 8;
+// How far either side of the slice the sonar picks up fish, in pixels of the fishing view
+const SONAR_WIDTH: f32 = 160.;
+
+const VIEW_HEIGHT: f32 = 720.;
+
+const SKY_COLOR: Color = Color::srgba(0.1, 0.1, 0.15, 0.8);
+const WATER_COLOR: Color = Color::srgba(0.1, 0.35, 0.7, 0.85);
+const BOTTOM_COLOR: Color = Color::srgb(0.45, 0.35, 0.2);
+const LURE_COLOR: Color = Color::srgb(1., 0.85, 0.1);
+const FISH_COLOR: Color = Color::srgb(1., 0.3, 0.3);
+const HOOKED_COLOR: Color = Color::srgb(0.3, 1., 0.4);
+
+#[derive(Component)]
+pub struct DepthInset;
+
+#[derive(Component)]
+pub struct InsetColumn(usize);
+
+#[derive(Component)]
+pub struct InsetLure;
+
+#[derive(Component)]
+pub struct InsetFish(usize);
+
+#[derive(Component)]
+pub struct InsetLabel;
+
+fn absolute(left: f32, top: f32, width: f32, height: f32) -> Style {
+    Style {
+        position_type: PositionType::Absolute,
+        left: Val::Px(left),
+        top: Val::Px(top),
+        width: Val::Px(width),
+        height: Val::Px(height),
+        ..default()
+    }
+}
+
+pub fn spawn_depth_inset(mut commands: Commands, asset_server: Res<AssetServer>) {
+    let column_width = INSET_WIDTH / INSET_COLUMNS as f32;
+
+    commands
+        .spawn((
+            NodeBundle {
+                style: Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(10.),
+                    right: Val::Px(10.),
+                    width: Val::Px(INSET_WIDTH),
+                    height: Val::Px(INSET_HEIGHT),
+                    ..default()
+                },
+                background_color: SKY_COLOR.into(),
+                visibility: Visibility::Hidden,
+                ..default()
+            },
+            DepthInset,
+        ))
+        .with_children(|parent| {
+            parent.spawn(NodeBundle {
+                style: absolute(0., INSET_SURFACE, INSET_WIDTH, INSET_HEIGHT - INSET_SURFACE),
+                background_color: WATER_COLOR.into(),
+                ..default()
+            });
+
+            for i in 0..INSET_COLUMNS {
+                parent.spawn((
+                    NodeBundle {
+                        style: absolute(i as f32 * column_width, INSET_HEIGHT, column_width, 0.),
+                        background_color: BOTTOM_COLOR.into(),
+                        ..default()
+                    },
+                    InsetColumn(i),
+                ));
+            }
+
+            for i in 0..MAX_SONAR_FISH {
+                parent.spawn((
+                    NodeBundle {
+                        style: absolute(0., 0., 10., 5.),
+                        background_color: FISH_COLOR.into(),
+                        visibility: Visibility::Hidden,
+                        ..default()
+                    },
+                    InsetFish(i),
+                ));
+            }
+
+            parent.spawn((
+                NodeBundle {
+                    style: absolute(0., 0., 6., 6.),
+                    background_color: LURE_COLOR.into(),
+                    visibility: Visibility::Hidden,
+                    ..default()
+                },
+                InsetLure,
+            ));
+
+            parent.spawn((
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: asset_server.load("fonts/pixel.ttf"),
+                        font_size: 28.0,
+                        color: Color::WHITE,
+                    },
+                )
+                .with_style(Style {
+                    position_type: PositionType::Absolute,
+                    top: Val::Px(2.),
+                    left: Val::Px(5.),
+                    ..default()
+                }),
+                InsetLabel,
+            ));
+        });
+}
+
+pub fn update_depth_inset(
+    interface: Res<State<CurrentInterface>>,
+    fishing_location: Res<State<FishingLocal>>,
+    bottom: Res<Bottom>,
+    tide: Res<Tide>,
+    rod: Query<&FishingRod>,
+    lure: Query<(&PhysicsObject, &Visibility), (With<Lure>, Without<DepthInset>, Without<InsetLure>, Without<InsetFish>)>,
+    fish: Query<(&Transform, &Species, &FishingLocal), (With<MysteryFish>, With<InPond>)>,
+    hooked_fish: Query<&PhysicsObject, (With<Fish>, With<Hooked>)>,
+    mut inset: Query<&mut Visibility, (With<DepthInset>, Without<InsetLure>, Without<InsetFish>)>,
+    mut columns: Query<(&InsetColumn, &mut Style), (Without<InsetLure>, Without<InsetFish>)>,
+    mut lure_marker: Query<(&mut Style, &mut Visibility), (With<InsetLure>, Without<InsetFish>, Without<InsetColumn>)>,
+    mut fish_markers: Query<(&InsetFish, &mut Style, &mut Visibility, &mut BackgroundColor), (Without<InsetLure>, Without<InsetColumn>)>,
+    mut label: Query<&mut Text, With<InsetLabel>>,
+) {
+    let Ok(mut inset_visibility) = inset.get_single_mut() else {
+        return;
+    };
+
+    if !interface.eq(&CurrentInterface::Fishing) {
+        *inset_visibility = Visibility::Hidden;
+        return;
+    }
+    *inset_visibility = Visibility::Visible;
+
+    let local = fishing_location.get();
+    let (lure_physics, lure_visibility) = lure.single();
+    let lure_out = *lure_visibility != Visibility::Hidden;
+
+    // The slice runs straight out from the shore under the lure, or under the
+    // rod tip before a cast
+    let slice_x = if lure_out {
+        lure_physics.position.x
+    } else {
+        rod.get_single().map_or(FISHING_ROOM_X, |rod_info| rod_info.tip_pos.x)
+    };
+    let shore_y = FISHING_ROOM_Y - VIEW_HEIGHT / 2.;
+    let point_at = |i: f32| Vec3::new(slice_x, shore_y + i / INSET_COLUMNS as f32 * VIEW_HEIGHT, 0.);
+
+    // Scale so the deepest water in the slice just fits the panel
+    let water_depth = |position: Vec3| bottom.depth_at(local, position) + tide.depth_at(local);
+    let max_depth = (0..=INSET_COLUMNS)
+        .map(|i| water_depth(point_at(i as f32)))
+        .fold(1., f32::max);
+    let pixels_per_meter = (INSET_HEIGHT - INSET_SURFACE) / max_depth;
+    let to_left = |y: f32| (y - shore_y) / VIEW_HEIGHT * INSET_WIDTH;
+    let to_top = |depth: f32| INSET_SURFACE + depth.clamp(0., max_depth) * pixels_per_meter;
+
+    for (column, mut style) in columns.iter_mut() {
+        let top = to_top(water_depth(point_at(column.0 as f32 + 0.5)));
+        style.top = Val::Px(top);
+        style.height = Val::Px(INSET_HEIGHT - top);
+    }
+
+    let (mut lure_style, mut lure_marker_visibility) = lure_marker.single_mut();
+    let mut label_text = label.single_mut();
+    let bottom_here = water_depth(lure_physics.position.with_x(slice_x));
+
+    if lure_out {
+        *lure_marker_visibility = Visibility::Inherited;
+        lure_style.left = Val::Px(to_left(lure_physics.position.y) - 3.);
+        lure_style.top = Val::Px(to_top(lure_physics.depth_meters()) - 3.);
+        label_text.sections[0].value =
+            format!("Lure {:.1}m  Bottom {:.1}m", lure_physics.depth_meters(), bottom_here);
+    } else {
+        *lure_marker_visibility = Visibility::Hidden;
+        label_text.sections[0].value = format!("Bottom {:.1}m", water_depth(point_at(INSET_COLUMNS as f32 / 2.)));
+    }
+
+    // Sonar returns, the hooked fish first then anything swimming near the slice
+    let mut echoes: Vec<(Vec3, f32, Color)> = hooked_fish
+        .iter()
+        .map(|fish_physics| (fish_physics.position, fish_physics.depth_meters(), HOOKED_COLOR))
+        .collect();
+
+    for (transform, species, fish_local) in fish.iter() {
+        let position = transform.translation;
+        if fish_local != local || (position.x - slice_x).abs() > SONAR_WIDTH {
+            continue;
+        }
+
+        // Fish hold the middle of their depth band, or hug the bottom where it's shallower
+        let depth = ((species.depth.0 + species.depth.1) as f32 / 2.).min(water_depth(position));
+        echoes.push((position, depth, FISH_COLOR));
+    }
+
+    for (marker, mut style, mut visibility, mut color) in fish_markers.iter_mut() {
+        match echoes.get(marker.0) {
+            Some((position, depth, echo_color)) => {
+                *visibility = Visibility::Inherited;
+                *color = (*echo_color).into();
+                style.left = Val::Px(to_left(position.y) - 5.);
+                style.top = Val::Px(to_top(*depth) - 2.5);
+            }
+            None => *visibility = Visibility::Hidden,
+        }
+    }
+}
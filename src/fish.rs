@@ -122,7 +122,7 @@ pub fn fish_update(
         region: Res<State<Region>>,
     )
     {
-        if time.timer.just_finished() {
+        if time.hour_just_changed() {
             for (mut fish, entity_id, species, hunger_cpt) in aging_fish.iter_mut(){
                 let mut w: bool = false;
                 let mut t: bool = false;
@@ -130,12 +130,12 @@ pub fn fish_update(
                 if species.weather == *current_weather {
                     w = true;
                 }
-                if species.time_of_day.0 <= time.hour as usize && species.time_of_day.1 >= time.hour as usize {
+                if time.is_within(&species.time_of_day) {
                     t = true;
                 }
                 
                 let mut fish_age = fish.age;
-                fish.update_fish_traits(hunger_cpt.index_cpt(true, 0, t, w, fish_age));
+                fish.update_fish_traits(hunger_cpt.hunger_prob(0, t, w, fish_age, time.bright_moon(), current_weather));
                 println!("Age: {}\nHunger: {}", fish.age, fish.hunger);
                 if fish.is_alive == false {
                     commands.entity(entity_id).despawn();
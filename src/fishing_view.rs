@@ -1,14 +1,21 @@
 extern crate rand;
 
+use crate::bottom::*;
 use crate::fish::*;
 use crate::gameday::*;
 use crate::interface::*;
+use crate::hook_hold::*;
 use crate::inventory::*;
+use crate::jump::*;
+use crate::junk::*;
 use crate::map::*;
 use crate::physics::*;
 use crate::prob_calc::*;
 use crate::resources::*;
+use crate::snag::*;
 use crate::species::*;
+use crate::water_clarity::*;
+use crate::water_temperature::*;
 use crate::weather::*;
 use crate::window::*;
 use bevy::prelude::*;
@@ -33,6 +40,12 @@ pub const PARTICLECOUNT: usize = 10;
 
 const CATCH_MARGIN: f32 = 30.;
 
+// How close the lure has to be to a fish's shadow for it to notice
+const LURE_SENSE_RANGE: f32 = 50.;
+// Metres above or below its depth band a fish will still notice a lure
+const DEPTH_SENSE_RANGE: f32 = 10.;
+const FOG_SENSE_RANGE: f32 = 25.;
+
 const DEPTH_DECAY: f32 = 40.;
 
 pub const FISHING_ROOM_CENTER: Vec2 = Map::get_area_center(0, -2);
@@ -53,8 +66,35 @@ const ROD_MIN_ROTATION: f32 = PI / 6.;
 const ROD_MAX_ROTATION: f32 = 5. / 6. * PI;
 const ROD_ROTATION_SPEED: f32 = PI / 2.;
 
-const MAX_CAST_DISTANCE: f32 = 400.;
-const CASTING_SPEED: f32 = 250.;
+// Most line that can be out, past this the line pulls tight and the lure drops
+const MAX_CAST_DISTANCE: f32 = 620.;
+// Casts are worked out in metres and drawn at this scale
+const CAST_PIXELS_PER_METER: f32 = 12.;
+// How fast the rod swings through a full power cast, radians per second
+const CAST_SWING_SPEED: f32 = 25.;
+const CAST_ELEVATION: f32 = 35. * PI / 180.;
+// Metres above the water the lure is let go
+const CAST_RELEASE_HEIGHT: f32 = 1.5;
+pub const AIR_GRAVITY: f32 = 9.81;
+const AIR_DRAG: f32 = 0.004;
+// Drag from the line trailing out behind the lure, same units as cd * sa
+const LINE_DRAG_AREA: f32 = 15.;
+// How much bigger the lure looks per metre it is in the air
+const CAST_HEIGHT_SCALE: f32 = 0.05;
+// Load bent into the rod by a full power swing, it springs back from this on
+// release. Only the drawn bend, launch_speed already covers the rod's loading.
+const CAST_WHIP_LOAD: f32 = 6000.;
+// Seconds per step when working out where a cast will land
+const CAST_PREDICT_STEP: f32 = 1. / 60.;
+// How far a full length cast by a beginner can stray from where it was aimed, in pixels
+const MAX_CAST_SCATTER: f32 = 60.;
+// Share of the scatter that practice takes away
+const SKILL_SCATTER_REDUCTION: f32 = 0.8;
+// Casts it takes to get most of the way to full skill
+const SKILL_CASTS: f32 = 40.;
+// Extra scatter from gusts, pixels per m/s of wind
+const WIND_SCATTER: f32 = 3.;
+const RETICLE_COLOR: Color = Color::srgba(1., 1., 1., 0.6);
 const REEL_IN_SPEED: f32 = 150.;
 
 lazy_static! {
@@ -106,6 +146,17 @@ pub enum FishingLocal {
     Pond2,
     Ocean,
 }
+
+impl FishingLocal {
+    // Region whose weather sits over the fishing spot
+    pub fn region(&self) -> Region {
+        match self {
+            FishingLocal::Ocean => Region::Shore,
+            _ => Region::West,
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct HookedDebris {
     pub drag_increase: f32,
@@ -133,6 +184,10 @@ struct PowerBar {
 #[derive(Component)]
 pub struct MysteryFish;
 
+// Fish shadow the fog is hiding, it comes back once the fog lifts
+#[derive(Component)]
+pub struct FogHidden;
+
 #[derive(Component)]
 struct PhysicsFish;
 
@@ -144,6 +199,12 @@ pub struct FishingRod {
     pub segments: Vec<Entity>,
     pub line: Vec<Entity>,
     pub tip_pos: Vec3,
+    // Load the blank is carrying right now, it lags behind and overshoots the pull on it
+    pub load: f32,
+    pub load_speed: f32,
+    // Line tension smoothed by the blank without ringing, what the line actually feels
+    pub cushioned: f32,
+    pub cushioned_speed: f32,
 }
 
 #[derive(Component, Default)]
@@ -204,6 +265,12 @@ pub struct FishingRodType {
     pub flexural_strength: f32,
     pub shear_modulus: f32,
     pub blank_color: Color,
+    // How quickly the blank springs back, in bounces per second. Fast action
+    // rods bend near the tip and snap back, slow ones bend through the butt
+    // and soak up more of a lunge.
+    pub action: f32,
+    // How quickly the bouncing dies away, at 1 it doesn't bounce at all
+    pub damping: f32,
 }
 
 impl FishingRodType {
@@ -215,6 +282,8 @@ impl FishingRodType {
         shear_strength: f32,
         shear_modulus: f32,
         blank_color: Color,
+        action: f32,
+        damping: f32,
     ) -> Self {
         Self {
             texture,
@@ -224,9 +293,28 @@ impl FishingRodType {
             flexural_strength: shear_strength,
             shear_modulus,
             blank_color,
+            action,
+            damping,
         }
     }
 
+    // Spring constant of the blank at the tip, 3EI / L^3 for a hollow tube
+    pub fn stiffness(&self) -> f32 {
+        let inner = self.radius - self.thickness;
+        let second_moment = PI / 4. * (self.radius.powi(4) - inner.powi(4));
+        3. * self.shear_modulus * second_moment / self.length.powi(3)
+    }
+
+    // Speed the lure leaves the tip at. The swing gives the tip speed, and the
+    // lure pulling on the blank loads it like a spring that kicks on release,
+    // so longer softer rods and heavier lures throw harder.
+    pub fn launch_speed(&self, power: f32, lure_mass: f32) -> f32 {
+        let swing = power * CAST_SWING_SPEED;
+        let load = lure_mass * swing * swing * self.length;
+        let stored = load * load / (2. * self.stiffness());
+        swing * self.length + (2. * stored / lure_mass).sqrt()
+    }
+
     pub const NORMAL: FishingRodType = FishingRodType::new(
         "rods/default.png",
         0.75,
@@ -235,6 +323,8 @@ impl FishingRodType {
         3450E6,
         72E9,
         Color::BLACK,
+        4.,
+        0.3,
     );
     pub const SURF: FishingRodType = FishingRodType::new(
         "rods/surf.png",
@@ -244,6 +334,8 @@ impl FishingRodType {
         3450E6,
         72E9,
         Color::BLACK,
+        2.5,
+        0.4,
     );
 }
 
@@ -257,9 +349,83 @@ struct ExclamationTimer {
     pub timer: Timer,
 }
 
+// Lure in flight, offset from the rod tip in pixels with height and velocity in metres
+#[derive(Clone, Copy, Default)]
+pub struct CastFlight {
+    pub offset: Vec2,
+    pub height: f32,
+    pub velocity: Vec3,
+}
+
+impl CastFlight {
+    pub fn launch(rod_type: &FishingRodType, lure: &Lure, power: f32, direction: Vec2) -> Self {
+        let speed = rod_type.launch_speed(power, lure.mass);
+
+        Self {
+            offset: Vec2::ZERO,
+            height: CAST_RELEASE_HEIGHT,
+            velocity: (direction.normalize_or_zero() * speed * CAST_ELEVATION.cos()).extend(speed * CAST_ELEVATION.sin()),
+        }
+    }
+
+    pub fn step(&mut self, lure: &Lure, wind: Vec2, dt: f32) {
+        // Air drag pulls the lure towards moving with the wind
+        let relative_velocity = self.velocity - wind.extend(0.);
+        let drag = AIR_DRAG * (lure.cd.0 * lure.sa.0 + LINE_DRAG_AREA) * relative_velocity.length() * relative_velocity;
+        let acceleration = Vec3::new(0., 0., -AIR_GRAVITY) - drag / lure.mass;
+
+        self.velocity += acceleration * dt;
+        self.offset += self.velocity.truncate() * CAST_PIXELS_PER_METER * dt;
+        self.height += self.velocity.z * dt;
+
+        // Out of line, it stops going any further and falls
+        if self.offset.length() > MAX_CAST_DISTANCE {
+            self.offset = self.offset.clamp_length_max(MAX_CAST_DISTANCE);
+            self.velocity = Vec3::new(0., 0., self.velocity.z);
+        }
+    }
+
+    pub fn landed(&self) -> bool {
+        self.height <= 0.
+    }
+
+    // Where a cast would land with the wind as it is now
+    pub fn predict(rod_type: &FishingRodType, lure: &Lure, power: f32, direction: Vec2, wind: Vec2) -> Vec2 {
+        let mut flight = Self::launch(rod_type, lure, power, direction);
+
+        while !flight.landed() {
+            flight.step(lure, wind, CAST_PREDICT_STEP);
+        }
+
+        flight.offset
+    }
+}
+
+// How practiced the player is at casting, every cast helps
+#[derive(Resource, Default)]
+pub struct CastingSkill {
+    pub casts: u32,
+}
+
+impl CastingSkill {
+    // 0 for a beginner up towards 1
+    pub fn level(&self) -> f32 {
+        1. - f32::exp(-(self.casts as f32) / SKILL_CASTS)
+    }
+
+    // Radius in pixels a cast this long can land away from where it was aimed
+    pub fn scatter(&self, distance: f32, wind: Vec2) -> f32 {
+        MAX_CAST_SCATTER * (1. - SKILL_SCATTER_REDUCTION * self.level()) * distance / MAX_CAST_DISTANCE
+            + WIND_SCATTER * wind.length()
+    }
+}
+
+#[derive(Component)]
+struct CastReticle;
+
 #[derive(Component)]
 pub struct FishingLine {
-    pub cast_distance: f32,
+    pub flight: CastFlight,
     pub length: f32,
     pub start: Vec3,
     pub end: Vec3,
@@ -270,7 +436,7 @@ pub struct FishingLine {
 impl FishingLine {
     pub fn new(line_type: &'static FishingLineType) -> Self {
         Self {
-            cast_distance: 0.0,
+            flight: CastFlight::default(),
             length: 0.0,
             start: Vec3::ZERO,
             end: Vec3::ZERO,
@@ -286,27 +452,40 @@ impl FishingLine {
 pub struct FishingLineType {
     pub ultimate_tensile_strength: f32,
     pub color: Color,
+    // How easily fish notice the line in clear water, 0 to 1
+    pub visibility: f32,
 }
 
 impl FishingLineType {
-    pub const fn new(ultimate_tensile_strength: f32, color: Color) -> Self {
+    pub const fn new(ultimate_tensile_strength: f32, color: Color, visibility: f32) -> Self {
         Self {
             ultimate_tensile_strength,
             color,
+            visibility,
         }
     }
 
     pub const FLUOROCARBON: FishingLineType =
-        FishingLineType::new(3000., Color::srgb(0.1, 0.1, 0.8));
-    pub const BRAIDED: FishingLineType = FishingLineType::new(4000., Color::srgb(0.0, 0.7, 0.2));
+        FishingLineType::new(3000., Color::srgb(0.1, 0.1, 0.8), 0.05);
+    pub const BRAIDED: FishingLineType = FishingLineType::new(4000., Color::srgb(0.0, 0.7, 0.2), 0.8);
     pub const MONOFILILMENT: FishingLineType =
-        FishingLineType::new(2000., Color::srgb(0.9, 0.9, 0.9));
-    pub const GOLDEN: FishingLineType = FishingLineType::new(10000., Color::srgb(0.88, 0.77, 0.25));
+        FishingLineType::new(2000., Color::srgb(0.9, 0.9, 0.9), 0.35);
+    pub const GOLDEN: FishingLineType = FishingLineType::new(10000., Color::srgb(0.88, 0.77, 0.25), 1.);
 }
 
 #[derive(Component)]
 struct FishingLineSegment;
 
+// How the lure is being worked through the water
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RetrieveStyle {
+    #[default]
+    Still,
+    Steady,
+    Twitch,
+    Jig,
+}
+
 #[derive(Component, Default, Clone, Copy)]
 pub struct Lure {
     pub texture_index: usize,
@@ -315,6 +494,8 @@ pub struct Lure {
     pub cd: (f32, f32),
     pub sa: (f32, f32),
     pub name: &'static str,
+    // The retrieve the lure is made for
+    pub action: RetrieveStyle,
 }
 
 impl Lure {
@@ -325,6 +506,7 @@ impl Lure {
         cd: (f32, f32),
         sa: (f32, f32),
         name: &'static str,
+        action: RetrieveStyle,
     ) -> Self {
         Self {
             texture_index,
@@ -333,19 +515,22 @@ impl Lure {
             cd,
             sa,
             name,
+            action,
         }
     }
 
-    pub const BOBBER: Lure = Lure::new(0, 2.0, 1., (0.47, 0.47), (50., 50.), "Bobber");
-    pub const FROG: Lure = Lure::new(1, 2.0, 20., (0.14, 1.14), (40., 90.), "Frog Bait");
-    pub const FISH: Lure = Lure::new(2, 2.0, 150., (0.09, 0.86), (35., 70.), "Swim Bait");
+    // All three weigh the same, so how far each casts comes from its drag
+    // alone. The Swim Bait's low cd and sa let it out-cast the others.
+    pub const BOBBER: Lure = Lure::new(0, 2.0, 1., (0.47, 0.47), (50., 50.), "Bobber", RetrieveStyle::Still);
+    pub const FROG: Lure = Lure::new(1, 2.0, 20., (0.14, 1.14), (40., 90.), "Frog Bait", RetrieveStyle::Twitch);
+    pub const FISH: Lure = Lure::new(2, 2.0, 150., (0.09, 0.86), (35., 70.), "Swim Bait", RetrieveStyle::Jig);
 }
 
 #[derive(Component)]
 struct Wave;
 
 #[derive(Component, Default)]
-struct Splash {
+pub struct Splash {
     pub position: Vec3,
 }
 
@@ -361,7 +546,7 @@ pub struct exclam_point;
 #[derive(Component)]
 pub struct PondObstruction;
 
-#[derive(Component, PartialEq)]
+#[derive(Component, PartialEq, Clone, Copy, Debug)]
 pub enum ObstType {
     Tree,
     Fissure,
@@ -369,6 +554,17 @@ pub enum ObstType {
     Debris,
 }
 
+impl ObstType {
+    pub fn name(&self) -> &'static str {
+        match self {
+            ObstType::Tree => "a sunken tree",
+            ObstType::Fissure => "the fissure",
+            ObstType::Pad => "the lily pads",
+            ObstType::Debris => "some junk",
+        }
+    }
+}
+
 #[derive(Component)]
 pub struct DebrisType {
     pub mass: f32,
@@ -386,9 +582,6 @@ impl DebrisType {
             height,
         }
     }
-
-    pub const WATER_BOTTLE: DebrisType = DebrisType::new(0.4, 0.3, 30., 42.);
-    pub const BUSH: DebrisType = DebrisType::new(2.0, 0.5, 32., 40.);
 }
 
 //FISH THING
@@ -457,6 +650,8 @@ impl Plugin for FishingViewPlugin {
 
         app.init_state::<FishingState>()
             .insert_resource(ProbTimer::new(2.))
+            .init_resource::<CastingSkill>()
+            .init_resource::<HookShy>()
             .add_systems(Startup, setup)
             .add_systems(
                 Update,
@@ -465,12 +660,13 @@ impl Plugin for FishingViewPlugin {
                     fish_area_lure
                         .run_if(in_state(FishingState::ReelingUnhooked))
                         .after(move_fish),
-                    (power_bar_cast, switch_rod, switch_line, switch_lure)
+                    (power_bar_cast, switch_rod, switch_line, switch_lure, mouse_aim)
                         .run_if(in_state(FishingState::Idle)),
                     rod_rotate.after(fish_area_lure),
+                    aim_cast_reticle.after(rod_rotate).after(mouse_aim),
                     (
                         calculate_water_force,
-                        (calculate_buoyancy_force, calculate_player_force).run_if(
+                        (calculate_buoyancy_force, calculate_wind_force, calculate_player_force).run_if(
                             in_state(FishingState::ReelingUnhooked)
                                 .or_else(in_state(FishingState::ReelingHooked)),
                         ),
@@ -479,8 +675,24 @@ impl Plugin for FishingViewPlugin {
                     calculate_fish_force
                         .after(calculate_water_force)
                         .after(calculate_buoyancy_force)
+                        .after(calculate_wind_force)
                         .after(calculate_player_force),
                     simulate_physics.after(calculate_fish_force),
+                    track_lure_motion
+                        .run_if(in_state(FishingState::ReelingUnhooked))
+                        .after(snag_lure),
+                    (
+                        snag_lure.run_if(in_state(FishingState::ReelingUnhooked)),
+                        wrap_line.run_if(in_state(FishingState::ReelingHooked)),
+                    )
+                        .after(simulate_physics)
+                        .before(bend_fishing_rod),
+                    (
+                        fish_jump.after(calculate_fish_force).before(simulate_physics),
+                        fish_airborne.after(simulate_physics).before(move_physics_objects),
+                        hold_hook.after(simulate_physics).before(is_line_broken),
+                    )
+                        .run_if(in_state(FishingState::ReelingHooked)),
                     (
                         bend_fishing_rod,
                         handle_debris.run_if(
@@ -506,15 +718,17 @@ impl Plugin for FishingViewPlugin {
                     adjust_fishing_line_size.after(animate_fishing_line),
                     draw_fishing_line.after(adjust_fishing_line_size),
                     animate_splash.after(cast_line),
+                    fog_hides_fish,
                 )
                     .run_if(in_state(CurrentInterface::Fishing)),
             )
             .add_systems(
                 OnEnter(CurrentInterface::Fishing),
-                (fishing_transition, switch_fishing_area),
+                (fishing_transition, switch_fishing_area, stock_junk),
             )
-            .add_systems(OnExit(CurrentInterface::Fishing), overworld_transition)
+            .add_systems(OnExit(CurrentInterface::Fishing), (overworld_transition, clear_junk))
             .add_systems(OnEnter(FishingState::Casting), begin_cast)
+            .add_systems(OnEnter(FishingState::Idle), (clear_snag, drop_junk, clear_airborne))
             .add_systems(
                 OnTransition {
                     exited: FishingState::ReelingUnhooked,
@@ -528,11 +742,15 @@ impl Plugin for FishingViewPlugin {
                     exited: FishingState::ReelingHooked,
                     entered: FishingState::Idle,
                 },
-                reset_interface,
+                (reset_interface, remember_lost_fish),
             )
             .add_systems(
                 Update,
                 fish_update.run_if(in_state(CurrentInterface::Fishing)),
+            )
+            .add_systems(
+                Update,
+                ((spawn_junk, drift_junk).run_if(in_state(CurrentInterface::Fishing)), collect_junk),
             );
     }
 }
@@ -630,7 +848,7 @@ fn setup(
         MysteryFish,
         FishingLocal::Pond1,
         HungerCpt::new(BASS.time_of_day),
-        HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.catch_prob),
+        HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.temp_pref, BASS.catch_prob),
     ));
 
     commands.spawn((
@@ -674,7 +892,7 @@ fn setup(
         MysteryFish,
         FishingLocal::Pond2,
         HungerCpt::new(BASS.time_of_day),
-        HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.catch_prob),
+        HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.temp_pref, BASS.catch_prob),
     ));
     let fish_bass_handle: Handle<Image> = asset_server.load("fish/bass.png");
     wave = spawn_waves(&mut commands, &waves_sheet_handle, &wave_layout_handle);
@@ -715,22 +933,22 @@ fn setup(
             age: 6.0,
             hunger: 1.0,
         },
-        PhysicsObject {
-            mass: 2.0,
-            position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y + 100., 0.),
-            rotation: Vec3::ZERO,
-            velocity: Vec3::ZERO,
-            forces: Forces::default(),
-            cd: BASS.cd,
-            sa: (5.0 * 5.0, 5.0 * 8.0),
-            waves: wave,
-        },
+        PhysicsObject::new(
+            2.0,
+            Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y + 100., 0.),
+            Quat::IDENTITY,
+            Vec3::ZERO,
+            Forces::default(),
+            BASS.cd,
+            (5.0 * 5.0, 5.0 * 8.0),
+            wave,
+        ),
         InPond,
         Collision,
         PhysicsFish,
         FishingLocal::Pond2,
         HungerCpt::new(BASS.time_of_day),
-        HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.catch_prob),
+        HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.temp_pref, BASS.catch_prob),
     ));
 
     //FISH BOX
@@ -776,7 +994,7 @@ fn setup(
         MysteryFish,
         FishingLocal::Pond1,
         HungerCpt::new(CATFISH.time_of_day),
-        HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.catch_prob),
+        HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.temp_pref, CATFISH.catch_prob),
     ));
 
     let fish_bass_handle: Handle<Image> = asset_server.load("fish/bass.png");
@@ -818,22 +1036,22 @@ fn setup(
             age: 6.0,
             hunger: 1.0,
         },
-        PhysicsObject {
-            mass: 2.0,
-            position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y + 100., 0.),
-            rotation: Vec3::ZERO,
-            velocity: Vec3::ZERO,
-            forces: Forces::default(),
-            cd: BASS.cd,
-            sa: (5.0 * 5.0, 5.0 * 8.0),
-            waves: wave,
-        },
+        PhysicsObject::new(
+            2.0,
+            Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y + 100., 0.),
+            Quat::IDENTITY,
+            Vec3::ZERO,
+            Forces::default(),
+            BASS.cd,
+            (5.0 * 5.0, 5.0 * 8.0),
+            wave,
+        ),
         InPond,
         Collision,
         PhysicsFish,
         FishingLocal::Pond1,
         HungerCpt::new(BASS.time_of_day),
-        HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.catch_prob),
+        HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.temp_pref, BASS.catch_prob),
     ));
 
     let fish_bass_handle: Handle<Image> = asset_server.load("fish/catfish.png");
@@ -875,22 +1093,22 @@ fn setup(
             age: 6.0,
             hunger: 1.0,
         },
-        PhysicsObject {
-            mass: 3.0,
-            position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y + 100., 0.),
-            rotation: Vec3::ZERO,
-            velocity: Vec3::ZERO,
-            forces: Forces::default(),
-            cd: CATFISH.cd,
-            sa: (5.0 * 5.0, 5.0 * 8.0),
-            waves: wave,
-        },
+        PhysicsObject::new(
+            3.0,
+            Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y + 100., 0.),
+            Quat::IDENTITY,
+            Vec3::ZERO,
+            Forces::default(),
+            CATFISH.cd,
+            (5.0 * 5.0, 5.0 * 8.0),
+            wave,
+        ),
         InPond,
         Collision,
         PhysicsFish,
         FishingLocal::Pond1,
         HungerCpt::new(CATFISH.time_of_day),
-        HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.catch_prob),
+        HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.temp_pref, CATFISH.catch_prob),
     ));
 
     // HUD background
@@ -961,6 +1179,18 @@ fn setup(
         PowerBar { power: 0. },
     ));
 
+    // Where the next cast should land, sized to how far it might stray
+    commands.spawn((
+        MaterialMesh2dBundle {
+            mesh: Mesh2dHandle(meshes.add(Annulus::new(0.8, 1.))),
+            material: materials.add(RETICLE_COLOR),
+            transform: Transform::from_xyz(FISHING_ROOM_X, FISHING_ROOM_Y, 955.),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        CastReticle,
+    ));
+
     let player_fishing_handle = asset_server.load("fishing_view/back_fishing_sprite.png");
     commands.spawn((SpriteBundle {
         texture: player_fishing_handle.clone(),
@@ -1021,6 +1251,10 @@ fn setup(
             PLAYER_POSITION.y + default_rod_type.length * PIXELS_PER_METER,
             0.,
         ),
+        load: 0.,
+        load_speed: 0.,
+        cushioned: 0.,
+        cushioned_speed: 0.,
     };
 
     // Fishing rod and attached line segments
@@ -1155,18 +1389,19 @@ fn setup(
             layout: lure_layout_handle.clone(),
             index: 0,
         },
-        PhysicsObject {
-            mass: 2.0,
-            position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y + 100., 0.),
-            rotation: Vec3::ZERO,
-            velocity: Vec3::ZERO,
-            forces: Forces::default(),
-            cd: Lure::BOBBER.cd,
-            sa: Lure::BOBBER.sa,
-            waves: wave,
-        },
+        PhysicsObject::new(
+            2.0,
+            Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y + 100., 0.),
+            Quat::IDENTITY,
+            Vec3::ZERO,
+            Forces::default(),
+            Lure::BOBBER.cd,
+            Lure::BOBBER.sa,
+            wave,
+        ),
         Collision,
         Lure::BOBBER,
+        LureMotion::default(),
     ));
 
     //spawning in the lilypad
@@ -1213,79 +1448,6 @@ fn setup(
         FishingLocal::Pond2,
     ));
 
-    let debris_sheet_handle: Handle<Image> = asset_server.load("fishing_view/water_bottle.png");
-    let bush_debris_sheet_handle: Handle<Image> = asset_server.load("tiles/bush_no_shadow.png");
-
-    commands.spawn((
-        SpriteBundle {
-            texture: debris_sheet_handle.clone(),
-            transform: Transform {
-                translation: Vec3::new(FISHING_ROOM_X + 100., FISHING_ROOM_Y + 50., 901.),
-                ..default()
-            },
-            ..default()
-        },
-        PondObstruction,
-        ObstType::Debris,
-        DebrisType::WATER_BOTTLE,
-        DebrisHooked { hooked: false },
-        InPond,
-        FishingLocal::Pond1,
-    ));
-
-    commands.spawn((
-        SpriteBundle {
-            texture: bush_debris_sheet_handle.clone(),
-            transform: Transform {
-                translation: Vec3::new(FISHING_ROOM_X - 500., FISHING_ROOM_Y - 1000., 901.),
-                ..default()
-            },
-            visibility: Visibility::Visible,
-            ..default()
-        },
-        Collision,
-        PondObstruction,
-        ObstType::Debris,
-        DebrisType::BUSH,
-        DebrisHooked { hooked: false },
-        InPond,
-        FishingLocal::Pond1,
-    ));
-
-    commands.spawn((
-        SpriteBundle {
-            texture: debris_sheet_handle.clone(),
-            sprite: Sprite { ..default() },
-            visibility: Visibility::Visible,
-            transform: Transform {
-                translation: Vec3::new(-8000., -8000., 901.),
-                ..default()
-            },
-            ..default()
-        },
-        PondObstruction,
-        ObstType::Debris,
-        DebrisType::WATER_BOTTLE,
-        InPond,
-        FishingLocal::Pond2,
-    ));
-
-    commands.spawn((
-        SpriteBundle {
-            texture: debris_sheet_handle.clone(),
-            transform: Transform {
-                translation: Vec3::new(-8000., -8000., 901.),
-                ..default()
-            },
-            ..default()
-        },
-        PondObstruction,
-        ObstType::Debris,
-        DebrisType::WATER_BOTTLE,
-        InPond,
-        FishingLocal::Ocean,
-    ));
-
     commands.spawn((
         SpriteBundle {
             texture: deep_sheet_handle.clone(),
@@ -1328,6 +1490,7 @@ fn move_fish(
     >,
     time: Res<Time>,
     mut config: ResMut<DirectionTimer>,
+    bottom: Res<Bottom>,
     //mut fish_direction: ResMut<FishBoundsDir>
 ) {
     let mut rng = rand::thread_rng();
@@ -1438,8 +1601,11 @@ fn move_fish(
 
                  */
 
+        // Fish won't swim into water too shallow for them
         let holdx: Vec3 = fish_pos.translation + fish_details.change_x;
-        if (holdx.x) >= (-640. + 160.) && (holdx.x) <= (431. - 160.) {
+        if (holdx.x) >= (-640. + 160.) && (holdx.x) <= (431. - 160.)
+            && bottom.can_swim(&*fishLoc, fish_pos.translation, holdx, fish_species.depth)
+        {
             //println!("{:?}", fish_pos.translation);
             fish_pos.translation += fish_details.change_x;
         } else {
@@ -1447,7 +1613,9 @@ fn move_fish(
             // println!("holdx = {:?}", holdx);
         }
         let holdy: Vec3 = fish_pos.translation + fish_details.change_y;
-        if (holdy.y) >= (-1400. - 224. + 90.) && (holdy.y) <= (-1400. + 360. - 90.) {
+        if (holdy.y) >= (-1400. - 224. + 90.) && (holdy.y) <= (-1400. + 360. - 90.)
+            && bottom.can_swim(&*fishLoc, fish_pos.translation, holdy, fish_species.depth)
+        {
             //println!("fish going up");
             fish_pos.translation += fish_details.change_y;
         } else {
@@ -1560,9 +1728,6 @@ fn switch_fishing_area(
 
                     obstPos.translation =
                         Vec3::new(FISHING_ROOM_X - 300., FISHING_ROOM_Y - 100., 901.);
-                } else if *obstType == ObstType::Debris {
-                    obstPos.translation =
-                        Vec3::new(FISHING_ROOM_X - 500., FISHING_ROOM_Y + 50., 901.);
                 }
             } else {
                 obstPos.translation = Vec3::new(-8000., -8000., 901.);
@@ -1594,9 +1759,6 @@ fn switch_fishing_area(
                         Vec3::new(FISHING_ROOM_X - 160., FISHING_ROOM_Y + 300., 901.);
                 } else if *obstType == ObstType::Fissure {
                     obstPos.translation = Vec3::new(FISHING_ROOM_X + 260., FISHING_ROOM_Y, 901.);
-                } else if *obstType == ObstType::Debris {
-                    obstPos.translation =
-                        Vec3::new(FISHING_ROOM_X - 350., FISHING_ROOM_Y + 200., 901.);
                 }
             } else {
                 obstPos.translation = Vec3::new(-8000., -8000., 901.);
@@ -1630,9 +1792,6 @@ fn switch_fishing_area(
                 } else if *obstType == ObstType::Fissure {
                     obstPos.translation =
                         Vec3::new(FISHING_ROOM_X - 360., FISHING_ROOM_Y - 100., 901.);
-                } else if *obstType == ObstType::Debris {
-                    obstPos.translation =
-                        Vec3::new(FISHING_ROOM_X + 260., FISHING_ROOM_Y + 300., 901.);
                 }
             } else {
                 obstPos.translation = Vec3::new(-8000., -8000., 901.);
@@ -1782,7 +1941,7 @@ fn fishPopulation(
                     MysteryFish,
                     FishingLocal::Pond1,
                     HungerCpt::new(BASS.time_of_day),
-                    HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.catch_prob),
+                    HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.temp_pref, BASS.catch_prob),
                 ));
                 commands.spawn((
                     SpriteBundle {
@@ -1820,22 +1979,22 @@ fn fishPopulation(
                         age: 1.0,
                         hunger: 10.0,
                     },
-                    PhysicsObject {
-                        mass: 2.0,
-                        position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
-                        rotation: Vec3::ZERO,
-                        velocity: Vec3::ZERO,
-                        forces: Forces::default(),
-                        cd: BASS.cd,
-                        sa: (5.0 * 5.0, 5.0 * 8.0),
-                        waves: wave,
-                    },
+                    PhysicsObject::new(
+                        2.0,
+                        Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
+                        Quat::IDENTITY,
+                        Vec3::ZERO,
+                        Forces::default(),
+                        BASS.cd,
+                        (5.0 * 5.0, 5.0 * 8.0),
+                        wave,
+                    ),
                     InPond,
                     Collision,
                     PhysicsFish,
                     FishingLocal::Pond1,
                     HungerCpt::new(BASS.time_of_day),
-                    HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.catch_prob),
+                    HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.temp_pref, BASS.catch_prob),
                 ));
             } else {
                 wave = spawn_waves(&mut commands, &waves_sheet_handle, &wave_layout_handle);
@@ -1884,7 +2043,7 @@ fn fishPopulation(
                     MysteryFish,
                     FishingLocal::Pond1,
                     HungerCpt::new(CATFISH.time_of_day),
-                    HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.catch_prob),
+                    HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.temp_pref, CATFISH.catch_prob),
                 ));
                 commands.spawn((
                     SpriteBundle {
@@ -1922,22 +2081,22 @@ fn fishPopulation(
                         age: 1.0,
                         hunger: 10.0,
                     },
-                    PhysicsObject {
-                        mass: 5.0,
-                        position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
-                        rotation: Vec3::ZERO,
-                        velocity: Vec3::ZERO,
-                        forces: Forces::default(),
-                        cd: CATFISH.cd,
-                        sa: (5.0 * 5.0, 5.0 * 8.0),
-                        waves: wave,
-                    },
+                    PhysicsObject::new(
+                        5.0,
+                        Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
+                        Quat::IDENTITY,
+                        Vec3::ZERO,
+                        Forces::default(),
+                        CATFISH.cd,
+                        (5.0 * 5.0, 5.0 * 8.0),
+                        wave,
+                    ),
                     InPond,
                     Collision,
                     PhysicsFish,
                     FishingLocal::Pond1,
                     HungerCpt::new(CATFISH.time_of_day),
-                    HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.catch_prob),
+                    HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.temp_pref, CATFISH.catch_prob),
                 ));
                 //spawning catfish
             }
@@ -2006,7 +2165,7 @@ fn fishPopulation(
                     MysteryFish,
                     FishingLocal::Pond2,
                     HungerCpt::new(BASS.time_of_day),
-                    HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.catch_prob),
+                    HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.temp_pref, BASS.catch_prob),
                 ));
                 commands.spawn((
                     SpriteBundle {
@@ -2044,22 +2203,22 @@ fn fishPopulation(
                         age: 1.0,
                         hunger: 1.0,
                     },
-                    PhysicsObject {
-                        mass: 2.0,
-                        position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
-                        rotation: Vec3::ZERO,
-                        velocity: Vec3::ZERO,
-                        forces: Forces::default(),
-                        cd: BASS.cd,
-                        sa: (5.0 * 5.0, 5.0 * 8.0),
-                        waves: wave,
-                    },
+                    PhysicsObject::new(
+                        2.0,
+                        Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
+                        Quat::IDENTITY,
+                        Vec3::ZERO,
+                        Forces::default(),
+                        BASS.cd,
+                        (5.0 * 5.0, 5.0 * 8.0),
+                        wave,
+                    ),
                     InPond,
                     Collision,
                     PhysicsFish,
                     FishingLocal::Pond2,
                     HungerCpt::new(BASS.time_of_day),
-                    HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.catch_prob),
+                    HookProbCpt::new(BASS.time_of_day, BASS.depth, BASS.temp_pref, BASS.catch_prob),
                 ));
             } else {
                 let fish_length = rng.gen_range(CATFISH.length.0..CATFISH.length.1);
@@ -2107,7 +2266,7 @@ fn fishPopulation(
                     MysteryFish,
                     FishingLocal::Pond2,
                     HungerCpt::new(CATFISH.time_of_day),
-                    HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.catch_prob),
+                    HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.temp_pref, CATFISH.catch_prob),
                 ));
                 commands.spawn((
                     SpriteBundle {
@@ -2145,22 +2304,22 @@ fn fishPopulation(
                         age: 1.0,
                         hunger: 10.0,
                     },
-                    PhysicsObject {
-                        mass: 5.0,
-                        position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
-                        rotation: Vec3::ZERO,
-                        velocity: Vec3::ZERO,
-                        forces: Forces::default(),
-                        cd: CATFISH.cd,
-                        sa: (5.0 * 5.0, 5.0 * 8.0),
-                        waves: wave,
-                    },
+                    PhysicsObject::new(
+                        5.0,
+                        Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
+                        Quat::IDENTITY,
+                        Vec3::ZERO,
+                        Forces::default(),
+                        CATFISH.cd,
+                        (5.0 * 5.0, 5.0 * 8.0),
+                        wave,
+                    ),
                     InPond,
                     Collision,
                     PhysicsFish,
                     FishingLocal::Pond2,
                     HungerCpt::new(CATFISH.time_of_day),
-                    HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.catch_prob),
+                    HookProbCpt::new(CATFISH.time_of_day, CATFISH.depth, CATFISH.temp_pref, CATFISH.catch_prob),
                 ));
             }
         } else {
@@ -2227,7 +2386,7 @@ fn fishPopulation(
                     MysteryFish,
                     FishingLocal::Ocean,
                     HungerCpt::new(MAHIMAHI.time_of_day),
-                    HookProbCpt::new(MAHIMAHI.time_of_day, MAHIMAHI.depth, MAHIMAHI.catch_prob),
+                    HookProbCpt::new(MAHIMAHI.time_of_day, MAHIMAHI.depth, MAHIMAHI.temp_pref, MAHIMAHI.catch_prob),
                 ));
                 commands.spawn((
                     SpriteBundle {
@@ -2265,36 +2424,22 @@ fn fishPopulation(
                         age: 1.0,
                         hunger: 10.0,
                     },
-                    PhysicsObject {
-                        mass: 2.0,
-                        position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
-                        rotation: Vec3::ZERO,
-                        velocity: Vec3::ZERO,
-                        forces: Forces::default(),
-                        cd: MAHIMAHI.cd,
-                        sa: (5.0 * 5.0, 5.0 * 8.0),
-                        waves: wave, //cd
-                                     //forces
-                                     //waves
-
-                                     /*
-                                                             pub struct PhysicsObject {
-                                         pub mass: f32,
-                                         pub position: Vec3,
-                                         pub rotation: Vec3,
-                                         pub velocity: Vec3,
-                                         pub forces: Forces,
-                                         pub cd: (f32, f32),
-                                         pub sa: (f32, f32),
-                                         pub waves: Entity
-                                     } */
-                    },
+                    PhysicsObject::new(
+                        2.0,
+                        Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
+                        Quat::IDENTITY,
+                        Vec3::ZERO,
+                        Forces::default(),
+                        MAHIMAHI.cd,
+                        (5.0 * 5.0, 5.0 * 8.0),
+                        wave,
+                    ),
                     InPond,
                     Collision,
                     PhysicsFish,
                     FishingLocal::Ocean,
                     HungerCpt::new(MAHIMAHI.time_of_day),
-                    HookProbCpt::new(MAHIMAHI.time_of_day, MAHIMAHI.depth, MAHIMAHI.catch_prob),
+                    HookProbCpt::new(MAHIMAHI.time_of_day, MAHIMAHI.depth, MAHIMAHI.temp_pref, MAHIMAHI.catch_prob),
                 ));
             } else if fish_num > 30 && fish_num <= 60 {
                 let fish_length = rng.gen_range(TUNA.length.0..TUNA.length.1);
@@ -2342,7 +2487,7 @@ fn fishPopulation(
                     MysteryFish,
                     FishingLocal::Ocean,
                     HungerCpt::new(TUNA.time_of_day),
-                    HookProbCpt::new(TUNA.time_of_day, TUNA.depth, TUNA.catch_prob),
+                    HookProbCpt::new(TUNA.time_of_day, TUNA.depth, TUNA.temp_pref, TUNA.catch_prob),
                 ));
                 commands.spawn((
                     SpriteBundle {
@@ -2381,22 +2526,22 @@ fn fishPopulation(
                         age: 1.0,
                         hunger: 1.0,
                     },
-                    PhysicsObject {
-                        mass: 10.0,
-                        position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
-                        rotation: Vec3::ZERO,
-                        velocity: Vec3::ZERO,
-                        forces: Forces::default(),
-                        cd: TUNA.cd,
-                        sa: (5.0 * 5.0, 5.0 * 8.0),
-                        waves: wave,
-                    },
+                    PhysicsObject::new(
+                        10.0,
+                        Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
+                        Quat::IDENTITY,
+                        Vec3::ZERO,
+                        Forces::default(),
+                        TUNA.cd,
+                        (5.0 * 5.0, 5.0 * 8.0),
+                        wave,
+                    ),
                     InPond,
                     Collision,
                     PhysicsFish,
                     FishingLocal::Ocean,
                     HungerCpt::new(TUNA.time_of_day),
-                    HookProbCpt::new(TUNA.time_of_day, TUNA.depth, TUNA.catch_prob),
+                    HookProbCpt::new(TUNA.time_of_day, TUNA.depth, TUNA.temp_pref, TUNA.catch_prob),
                 ));
             } else if fish_num >= 60 && fish_num < 90 {
                 wave = spawn_waves(&mut commands, &waves_sheet_handle, &wave_layout_handle);
@@ -2445,7 +2590,7 @@ fn fishPopulation(
                     MysteryFish,
                     FishingLocal::Ocean,
                     HungerCpt::new(SWORDFISH.time_of_day),
-                    HookProbCpt::new(SWORDFISH.time_of_day, SWORDFISH.depth, SWORDFISH.catch_prob),
+                    HookProbCpt::new(SWORDFISH.time_of_day, SWORDFISH.depth, SWORDFISH.temp_pref, SWORDFISH.catch_prob),
                 ));
                 commands.spawn((
                     SpriteBundle {
@@ -2484,22 +2629,22 @@ fn fishPopulation(
                         age: 1.0,
                         hunger: 10.0,
                     },
-                    PhysicsObject {
-                        mass: 10.0,
-                        position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
-                        rotation: Vec3::ZERO,
-                        velocity: Vec3::ZERO,
-                        forces: Forces::default(),
-                        cd: SWORDFISH.cd,
-                        sa: (5.0 * 5.0, 5.0 * 8.0),
-                        waves: wave,
-                    },
+                    PhysicsObject::new(
+                        10.0,
+                        Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
+                        Quat::IDENTITY,
+                        Vec3::ZERO,
+                        Forces::default(),
+                        SWORDFISH.cd,
+                        (5.0 * 5.0, 5.0 * 8.0),
+                        wave,
+                    ),
                     InPond,
                     Collision,
                     PhysicsFish,
                     FishingLocal::Ocean,
                     HungerCpt::new(SWORDFISH.time_of_day),
-                    HookProbCpt::new(SWORDFISH.time_of_day, SWORDFISH.depth, SWORDFISH.catch_prob),
+                    HookProbCpt::new(SWORDFISH.time_of_day, SWORDFISH.depth, SWORDFISH.temp_pref, SWORDFISH.catch_prob),
                 ));
             } else if fish_num >= 90 {
                 wave = spawn_waves(&mut commands, &waves_sheet_handle, &wave_layout_handle);
@@ -2551,6 +2696,7 @@ fn fishPopulation(
                     HookProbCpt::new(
                         REDHANDFISH.time_of_day,
                         REDHANDFISH.depth,
+                        REDHANDFISH.temp_pref,
                         REDHANDFISH.catch_prob,
                     ),
                 ));
@@ -2591,16 +2737,16 @@ fn fishPopulation(
                         age: 1.0,
                         hunger: 10.0,
                     },
-                    PhysicsObject {
-                        mass: 10.0,
-                        position: Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
-                        rotation: Vec3::ZERO,
-                        velocity: Vec3::ZERO,
-                        forces: Forces::default(),
-                        cd: REDHANDFISH.cd,
-                        sa: (5.0 * 5.0, 5.0 * 8.0),
-                        waves: wave,
-                    },
+                    PhysicsObject::new(
+                        10.0,
+                        Vec3::new(FISHING_ROOM_X, FISHING_ROOM_Y, 0.),
+                        Quat::IDENTITY,
+                        Vec3::ZERO,
+                        Forces::default(),
+                        REDHANDFISH.cd,
+                        (5.0 * 5.0, 5.0 * 8.0),
+                        wave,
+                    ),
                     InPond,
                     Collision,
                     PhysicsFish,
@@ -2609,6 +2755,7 @@ fn fishPopulation(
                     HookProbCpt::new(
                         REDHANDFISH.time_of_day,
                         REDHANDFISH.depth,
+                        REDHANDFISH.temp_pref,
                         REDHANDFISH.catch_prob,
                     ),
                 ));
@@ -2650,6 +2797,7 @@ fn fish_area_lure(
             &mut PhysicsObject,
             &mut Visibility,
             &Lure,
+            &LureMotion,
         ),
         (
             With<Lure>,
@@ -2695,10 +2843,28 @@ fn fish_area_lure(
     time: Res<Time>,
     mut config: ResMut<ExclamationTimer>,
     debris_details: Query<(&DebrisType, &DebrisHooked)>,
+    fishing_location: Res<State<FishingLocal>>,
+    // water and air conditions, grouped to stay under the system param limit
+    (water_temperature, water_clarity, pressure): (Res<WaterTemperature>, Res<WaterClarity>, Res<PressureState>),
+    // the line it might see, and whether it's been hooked lately
+    (line, hook_shy): (Query<&FishingLine>, Res<HookShy>),
 ) {
-    let (lure_transform, lure_entity_id, mut lure_physics, mut lure_vis, lure_details) =
+    let (lure_transform, lure_entity_id, mut lure_physics, mut lure_vis, lure_details, lure_motion) =
         lure.single_mut();
+    let retrieve = lure_motion.style();
+    // Where the lure really is right now, below the surface
+    let lure_depth = lure_physics.depth_meters();
+    let lure_temp = water_temperature.at(fishing_location.get(), lure_depth);
     let lure_position = lure_transform.translation;
+    let current_weather = weather.weather_by_region.get(region.get()).unwrap_or(&Weather::Sunny);
+    let sense_range = if *current_weather == Weather::Fog {
+        FOG_SENSE_RANGE
+    } else {
+        LURE_SENSE_RANGE
+    } * water_clarity.sight_factor(fishing_location.get());
+    let line_seen = line
+        .get_single()
+        .map_or(0., |line| water_clarity.line_seen(fishing_location.get(), line.line_type));
     //let (bob, tile) = lure.single_mut();
     //let (bob, tile, mut lure_vis) = lure.single_mut();
     //let (mut exclam_transform, mut exclam_vis) = exclamation.single_mut();
@@ -2709,14 +2875,22 @@ fn fish_area_lure(
         let fish_pos_loc = fish_pos.translation;
         let lure_position = lure_transform.translation;
 
+        // Fish keep to their own layer of the water and won't see a lure far above or below it
+        if lure_depth < fish_species.depth.0 as f32 - DEPTH_SENSE_RANGE
+            || lure_depth > fish_species.depth.1 as f32 + DEPTH_SENSE_RANGE
+        {
+            fish_details.touching_lure = false;
+            continue;
+        }
+
         //println!("fish {:?} {} x {} y \n lure:  {} x {} y ", fishes_details.name, fish_pos.translation.x, fish_pos.translation.y, lure_position.x, lure_position.y);
 
         //let lure_position = bob.translation;
 
-        if fish_pos_loc.y - 180. / 2. > lure_position.y + 50.
-            || fish_pos_loc.y + 180. / 2. < lure_position.y - 50.
-            || fish_pos_loc.x + 320. / 2. < lure_position.x - 50.
-            || fish_pos_loc.x - 320. / 2. > lure_position.x + 50.
+        if fish_pos_loc.y - 180. / 2. > lure_position.y + sense_range
+            || fish_pos_loc.y + 180. / 2. < lure_position.y - sense_range
+            || fish_pos_loc.x + 320. / 2. < lure_position.x - sense_range
+            || fish_pos_loc.x - 320. / 2. > lure_position.x + sense_range
         {
             //there is no hit
             fish_details.touching_lure = false;
@@ -2730,15 +2904,28 @@ fn fish_area_lure(
 
         //let (entity_id, mut fishy_details, fish_species, mut fish_physics, mut fishy_transform, mut fishy_vis) = fishes.single_mut();
 
+        let shyness = hook_shy.shyness(fish_details.id, timer.total_hours());
+        let conditions = BiteConditions {
+            weather: &weather,
+            pressure: &pressure,
+            region: region.get(),
+            timer: &timer,
+        };
+
         //ERROR HERE
         if hook_fish(
             (&mut fish_details, fish_species, hook_cpt),
-            &weather,
-            &region,
-            &timer,
+            &conditions,
             &mut prob_timer,
             &time,
-            lure_details,
+            &LurePresentation {
+                lure: lure_details,
+                depth: lure_depth,
+                temp: lure_temp,
+                retrieve,
+                line_seen,
+            },
+            shyness,
         ) {
             for (
                 entity_id,
@@ -2792,7 +2979,7 @@ fn fish_area_lure(
 
                     println!("fish name {:?}", fishy_details.name);
                     commands.entity(lure_entity_id).remove::<Hooked>(); //yes
-                    commands.entity(entity_id).insert(Hooked); //yes
+                    commands.entity(entity_id).insert((Hooked, HookHold::on_bite())); //yes
                     next_state.set(FishingState::ReelingHooked);
 
                     break;
@@ -2851,6 +3038,27 @@ fn fishing_transition(
     //powerbar is space A, D are rotational
 }
 
+// Fog hides the fish shadows, only the ones it hid come back once it lifts
+fn fog_hides_fish(
+    mut commands: Commands,
+    weather: Res<WeatherState>,
+    region: Res<State<Region>>,
+    mut fishes: Query<(Entity, &mut Visibility, Has<FogHidden>), With<MysteryFish>>,
+) {
+    let current_weather = weather.weather_by_region.get(region.get()).unwrap_or(&Weather::Sunny);
+    let foggy = *current_weather == Weather::Fog;
+
+    for (entity_id, mut vizi, fog_hidden) in fishes.iter_mut() {
+        if foggy && *vizi != Visibility::Hidden {
+            *vizi = Visibility::Hidden;
+            commands.entity(entity_id).insert(FogHidden);
+        } else if !foggy && fog_hidden {
+            *vizi = Visibility::Visible;
+            commands.entity(entity_id).remove::<FogHidden>();
+        }
+    }
+}
+
 fn overworld_transition(
     mut camera: Query<&mut Transform, With<Camera>>,
     //mut power_bar: Query<(&mut Transform, &mut Power), With<Bar>>,
@@ -2868,13 +3076,14 @@ fn overworld_transition(
 
 fn power_bar_cast(
     input: Res<ButtonInput<KeyCode>>,
+    mouse: Res<ButtonInput<MouseButton>>,
     time: Res<Time>,
     mut next_state: ResMut<NextState<FishingState>>,
     mut power_bar: Query<(&mut PowerBar, &mut Transform), With<PowerBar>>,
 ) {
     let (mut power_bar_info, mut power_bar_transform) = power_bar.single_mut();
 
-    if input.pressed(TUG) {
+    if input.pressed(TUG) || mouse.pressed(MouseButton::Left) {
         // Increase power
         power_bar_info.power = power_bar_info.power + POWER_FILL_SPEED * time.delta_seconds();
 
@@ -2885,7 +3094,7 @@ fn power_bar_cast(
         }
 
         power_bar_transform.translation.y = POWER_BAR_Y_OFFSET + power_bar_info.power;
-    } else if input.just_released(TUG) {
+    } else if input.just_released(TUG) || mouse.just_released(MouseButton::Left) {
         // Manual release
         next_state.set(FishingState::Casting);
     } else {
@@ -3034,36 +3243,65 @@ fn switch_lure(
 
     *lure = **new_lure;
 
-    *physics = PhysicsObject {
-        mass: new_lure.mass,
-        position: Vec3::ZERO,
-        rotation: Vec3::ZERO,
-        velocity: Vec3::ZERO,
-        forces: Forces::default(),
-        cd: new_lure.cd,
-        sa: new_lure.sa,
-        waves: physics.waves,
-    };
+    *physics = PhysicsObject::new(
+        new_lure.mass,
+        Vec3::ZERO,
+        Quat::IDENTITY,
+        Vec3::ZERO,
+        Forces::default(),
+        new_lure.cd,
+        new_lure.sa,
+        physics.waves,
+    );
 
     screen_texture.index = new_lure.texture_index;
     lure_texture.index = new_lure.texture_index;
+    println!("Switched to {}, made for a {:?} retrieve", new_lure.name, new_lure.action);
 }
 
 fn begin_cast(
     mut commands: Commands,
+    wind: Res<WindState>,
+    region: Res<State<Region>>,
+    skill: Res<CastingSkill>,
     power_bar: Query<&PowerBar>,
+    mut rod: Query<&mut FishingRod>,
     mut line: Query<&mut FishingLine>,
     mut lure: Query<(Entity, &Lure, &mut Visibility, &mut PhysicsObject), With<Lure>>,
 ) {
     let power_bar_info = power_bar.single();
+    let mut rod_info = rod.single_mut();
     let mut line_info = line.single_mut();
     let (entity_id, lure_info, mut lure_visibililty, mut lure_physics) = lure.single_mut();
 
-    line_info.cast_distance = power_bar_info.power / MAX_POWER * MAX_CAST_DISTANCE;
+    let mut rng = rand::thread_rng();
+    let wind_now = wind.get(region.get());
+    let power = power_bar_info.power / MAX_POWER;
+    let aim = Vec2::from_angle(rod_info.rotation);
+    let landing = CastFlight::predict(rod_info.rod_type, lure_info, power, aim, wind_now);
+    let distance = landing.length().max(1.);
+
+    // Miss the aim by up to the scatter, sideways by turning the cast a little
+    // and long or short by throwing a little harder or softer
+    let scatter = skill.scatter(distance, wind_now);
+    let miss = Vec2::from_angle(rng.gen_range(0. ..2. * PI)) * scatter * rng.gen::<f32>().sqrt();
+    let turn = miss.dot(aim.perp()) / distance;
+    let stretch = miss.dot(aim) / distance;
+
+    line_info.flight = CastFlight::launch(
+        rod_info.rod_type,
+        lure_info,
+        (power * (1. + stretch / 2.)).max(0.),
+        Vec2::from_angle(rod_info.rotation + turn),
+    );
     lure_physics.mass = lure_info.mass;
     lure_physics.cd = lure_info.cd;
     *lure_visibililty = Visibility::Visible;
     commands.entity(entity_id).insert(Hooked);
+
+    // The blank is loaded right up on the swing and whips through on release
+    rod_info.load = CAST_WHIP_LOAD * power;
+    rod_info.load_speed = 0.;
 }
 
 fn handle_debris(
@@ -3085,7 +3323,7 @@ fn handle_debris(
             || debris_pos.y <= attached_to.y - debris_info.height / 2.
             || debris_pos.x <= attached_to.x - debris_info.width / 2.
             || debris_pos.x >= attached_to.x + debris_info.width / 2.
-            || attached_to.y <= -5.
+            || hooked_object_physics.position.z <= -5. * DEPTH_UNITS_PER_METER
         {
             if debris_is_hooked.hooked {
                 debris_position.translation = attached_to;
@@ -3123,10 +3361,10 @@ fn is_done_reeling(
 
     commands.entity(entity_id).remove::<Hooked>();
 
-    // Despawn hooked debris
+    // Hooked junk goes in the inventory
     for (entity_id, is_hooked) in debris.iter() {
         if is_hooked.hooked {
-            commands.entity(entity_id).despawn();
+            commands.entity(entity_id).insert(Landed);
         }
     }
 
@@ -3156,7 +3394,7 @@ fn is_fish_caught(
         for (debris_id, debris_info, debris_hooked) in debris.iter() {
             if debris_hooked.hooked {
                 fish_physics.mass -= debris_info.mass;
-                commands.entity(debris_id).despawn();
+                commands.entity(debris_id).insert(Landed);
             }
         }
 
@@ -3193,38 +3431,115 @@ fn rod_rotate(
     rod_transform.rotation = Quat::from_rotation_z(rod_info.rotation);
 }
 
+// Point the rod at the mouse while it's moving
+fn mouse_aim(
+    mut cursor: EventReader<CursorMoved>,
+    camera: Query<(&Camera, &GlobalTransform)>,
+    mut fishing_rod: Query<(&mut FishingRod, &mut Transform), With<FishingRod>>,
+) {
+    let Some(moved) = cursor.read().last() else {
+        return;
+    };
+    let (camera, camera_transform) = camera.single();
+    let Some(target) = camera.viewport_to_world_2d(camera_transform, moved.position) else {
+        return;
+    };
+
+    let (mut rod_info, mut rod_transform) = fishing_rod.single_mut();
+    let aim = target - rod_transform.translation.xy();
+
+    // Behind the player the rod stops at whichever side is closer
+    let angle = if aim.y >= 0. {
+        f32::atan2(aim.y, aim.x)
+    } else if aim.x < 0. {
+        ROD_MAX_ROTATION
+    } else {
+        ROD_MIN_ROTATION
+    };
+
+    rod_info.rotation = angle.clamp(ROD_MIN_ROTATION, ROD_MAX_ROTATION);
+    rod_transform.rotation = Quat::from_rotation_z(rod_info.rotation);
+}
+
+// Ring showing where the cast will land if it's let go now, or at full power
+// before the power bar starts filling
+fn aim_cast_reticle(
+    state: Res<State<FishingState>>,
+    wind: Res<WindState>,
+    region: Res<State<Region>>,
+    skill: Res<CastingSkill>,
+    rod: Query<&FishingRod>,
+    power_bar: Query<&PowerBar>,
+    lure: Query<&Lure>,
+    mut reticle: Query<(&mut Transform, &mut Visibility), With<CastReticle>>,
+) {
+    let (mut reticle_transform, mut reticle_visibility) = reticle.single_mut();
+
+    if !state.eq(&FishingState::Idle) {
+        *reticle_visibility = Visibility::Hidden;
+        return;
+    }
+
+    let rod_info = rod.single();
+    let power_bar_info = power_bar.single();
+    let wind_now = wind.get(region.get());
+    let power = if power_bar_info.power > 0. {
+        power_bar_info.power
+    } else {
+        MAX_POWER
+    };
+
+    let landing = CastFlight::predict(
+        rod_info.rod_type,
+        lure.single(),
+        power / MAX_POWER,
+        Vec2::from_angle(rod_info.rotation),
+        wind_now,
+    );
+    let scatter = skill.scatter(landing.length(), wind_now).max(4.);
+
+    *reticle_visibility = Visibility::Visible;
+    reticle_transform.translation = (rod_info.tip_pos.xy() + landing).extend(955.);
+    reticle_transform.scale = Vec3::new(scatter, scatter, 1.);
+}
+
 fn cast_line(
     time: Res<Time>,
+    wind: Res<WindState>,
+    region: Res<State<Region>>,
     mut next_state: ResMut<NextState<FishingState>>,
     rod: Query<&FishingRod, With<FishingRod>>,
     mut line: Query<&mut FishingLine, With<FishingLine>>,
-    mut lure: Query<(&mut Transform, &mut PhysicsObject), (With<Lure>, Without<FishingRod>)>,
+    mut lure: Query<(&mut Transform, &mut PhysicsObject, &Lure), (With<Lure>, Without<FishingRod>)>,
     mut splash: Query<(&mut Splash, &mut Visibility), With<Splash>>,
+    mut skill: ResMut<CastingSkill>,
 ) {
     let rod_info = rod.single();
     let mut line_info = line.single_mut();
-    let (mut lure_transform, mut lure_physics) = lure.single_mut();
+    let (mut lure_transform, mut lure_physics, lure_info) = lure.single_mut();
     let (mut splash_info, mut splash_visibility) = splash.single_mut();
-    let angle_vector = Vec2::from_angle(rod_info.rotation).extend(0.);
 
-    line_info.length =
-        (line_info.length + CASTING_SPEED * time.delta_seconds()).min(line_info.cast_distance);
-    line_info.end = rod_info.tip_pos + line_info.length * angle_vector;
+    line_info.flight.step(lure_info, wind.get(region.get()), time.delta_seconds());
+    line_info.length = line_info.flight.offset.length();
+    line_info.end = rod_info.tip_pos + line_info.flight.offset.extend(0.);
 
-    if line_info.length == line_info.cast_distance {
+    //setting the position of the lure along with the physics location of the lure.
+    //also make sure that we are setting the lure to be a hooked object
+    lure_physics.position = line_info.end.with_z(line_info.flight.height.max(0.));
+    lure_physics.forces.water = Vec3::ZERO;
+    lure_transform.translation = line_info.end.with_z(950.);
+    lure_transform.scale = Vec3::splat(1. + line_info.flight.height.max(0.) * CAST_HEIGHT_SCALE);
+
+    if line_info.flight.landed() {
         // Cast finished
+        println!("Cast landed {:.1}m out", line_info.length / CAST_PIXELS_PER_METER);
+        skill.casts += 1;
         lure_physics.forces.gravity = Vec3::new(0., 0., -GRAVITY * lure_physics.mass);
-        line_info.length = line_info.cast_distance;
+        lure_transform.scale = Vec3::ONE;
         splash_info.position = line_info.end.with_z(902.);
         *splash_visibility = Visibility::Visible;
         next_state.set(FishingState::ReelingUnhooked);
     }
-
-    //setting the position of the lure along with the physics location of the lure.
-    //also make sure that we are setting the lure to be a hooked object
-    lure_physics.position = Vec3::new(line_info.end.x, line_info.end.y, lure_physics.position.z);
-    lure_physics.forces.water = Vec3::ZERO;
-    lure_transform.translation = line_info.end.with_z(950.);
 }
 
 fn animate_fishing_line(
@@ -3243,10 +3558,8 @@ fn animate_fishing_line(
     if !hooked_fish.is_empty() {
         // Reeling hooked
         let (fish_species, fish_physics) = hooked_fish.single();
-        let fish_offset = fish_species
-            .hook_pos
-            .rotate(Vec2::from_angle(fish_physics.rotation.z));
-        let fish_pos = fish_physics.position + fish_offset.extend(0.);
+        let fish_offset = fish_physics.rotation * fish_species.hook_pos.extend(0.);
+        let fish_pos = fish_physics.position + fish_offset;
         line_info.end = fish_pos;
     } else if state.eq(&FishingState::ReelingUnhooked) {
         // Reeling unhooked
@@ -3262,12 +3575,12 @@ fn reset_interface(
     mut power_bar: Query<&mut PowerBar>,
     mut line: Query<&mut FishingLine, With<FishingLine>>,
     mut splash: Query<&mut TextureAtlas, With<Splash>>,
-    mut lure: Query<(&mut PhysicsObject, &mut Visibility), With<Lure>>,
+    mut lure: Query<(&mut PhysicsObject, &mut Visibility, &mut LureMotion), With<Lure>>,
 ) {
     let mut power_bar_info = power_bar.single_mut();
     let mut line_info = line.single_mut();
     let mut splash_texture = splash.single_mut();
-    let (mut lure_physics, mut lure_visibility) = lure.single_mut();
+    let (mut lure_physics, mut lure_visibility, mut lure_motion) = lure.single_mut();
 
     line_info.length = 0.;
     line_info.start = Vec3::ZERO;
@@ -3275,6 +3588,7 @@ fn reset_interface(
     lure_physics.position.z = 0.;
     lure_physics.velocity = Vec3::ZERO;
     lure_physics.forces = Forces::default();
+    *lure_motion = LureMotion::default();
     *lure_visibility = Visibility::Hidden;
     splash_texture.index = 0;
     power_bar_info.power = 0.;
@@ -3285,7 +3599,7 @@ pub fn move_physics_objects(
 ) {
     for (physics_object, mut sprite, mut transform) in objects.iter_mut() {
         transform.translation = physics_object.position.with_z(transform.translation.z);
-        transform.rotation = Quat::from_rotation_z(physics_object.rotation.z);
+        transform.rotation = Quat::from_rotation_z(physics_object.heading());
 
         let new_alpha = if physics_object.position.z > 0. {
             1.
@@ -1,16 +1,180 @@
 use bevy::prelude::*;
+use std::f32::consts::PI;
+use std::fs::File;
+use std::io::*;
+use crate::fishing_view::*;
+use crate::tide::*;
 
-#[derive(Copy, Clone)]
+// Shape of one piece of a water current field. Positions are in pixels
+// relative to the centre of the fishing view.
+#[derive(Copy, Clone, Debug)]
+pub enum CurrentShape {
+    // Same flow everywhere
+    Uniform(Vec3),
+    // Inflow running from start to end, fastest along its centre line
+    Stream { start: Vec2, end: Vec2, width: f32, speed: f32 },
+    // Water circling a point, positive strength turns counterclockwise
+    Eddy { center: Vec2, radius: f32, strength: f32 },
+    // Narrow jet running out from the shore, fading with distance
+    Rip { origin: Vec2, direction: Vec2, width: f32, length: f32, speed: f32 },
+    // Flows along direction while the tide comes in and back out as it goes out
+    Tidal { direction: Vec2, speed: f32 },
+}
+
+impl CurrentShape {
+    pub fn velocity_at(&self, position: Vec2, tide_flow: f32) -> Vec3 {
+        match *self {
+            CurrentShape::Uniform(velocity) => velocity,
+            CurrentShape::Stream { start, end, width, speed } => {
+                let axis = end - start;
+                let length = axis.length();
+                if length == 0. {
+                    return Vec3::ZERO;
+                }
+                let direction = axis / length;
+                let along = (position - start).dot(direction);
+                let across = (position - start).perp_dot(direction);
+
+                if along < 0. || along > length || across.abs() > width / 2. {
+                    return Vec3::ZERO;
+                }
+
+                let profile = 1. - (2. * across / width).powi(2);
+                (speed * profile * direction).extend(0.)
+            }
+            CurrentShape::Eddy { center, radius, strength } => {
+                let offset = position - center;
+                let distance = offset.length();
+
+                if distance == 0. || distance > radius {
+                    return Vec3::ZERO;
+                }
+
+                let tangent = offset.perp() / distance;
+                (strength * f32::sin(PI * distance / radius) * tangent).extend(0.)
+            }
+            CurrentShape::Rip { origin, direction, width, length, speed } => {
+                let direction = direction.normalize_or_zero();
+                let along = (position - origin).dot(direction);
+                let across = (position - origin).perp_dot(direction);
+
+                if along < 0. || along > length || across.abs() > width / 2. {
+                    return Vec3::ZERO;
+                }
+
+                let profile = (1. - along / length) * (1. - (2. * across / width).powi(2));
+                (speed * profile * direction).extend(0.)
+            }
+            CurrentShape::Tidal { direction, speed } => {
+                (speed * tide_flow * direction.normalize_or_zero()).extend(0.)
+            }
+        }
+    }
+}
+
+// A current shape and the fishing spot it belongs to, None applies to every
+// spot in the area
+#[derive(Clone, Debug)]
+pub struct Current {
+    pub local: Option<FishingLocal>,
+    pub shape: CurrentShape,
+}
+
+#[derive(Clone)]
 pub struct FishingZone {
-    pub current: Vec3
+    pub currents: Vec<Current>
 }
 
 impl FishingZone {
-    // fn new(current: Vec3) -> Self {
-    //     Self { current }
-    // }
-
     pub const DEFAULT: FishingZone = FishingZone {
-        current: Vec3::ZERO
+        currents: Vec::new()
     };
-}
\ No newline at end of file
+
+    pub fn uniform(current: Vec3) -> Self {
+        Self {
+            currents: vec![Current { local: None, shape: CurrentShape::Uniform(current) }]
+        }
+    }
+
+    // Water velocity at a world position in the given fishing spot
+    pub fn current_at(&self, local: &FishingLocal, position: Vec3, tide: &Tide) -> Vec3 {
+        let relative = position.truncate() - FISHING_ROOM_CENTER;
+
+        self.currents
+            .iter()
+            .filter(|current| current.local.as_ref().is_none_or(|l| l == local))
+            .map(|current| current.shape.velocity_at(relative, tide.flow))
+            .sum()
+    }
+
+    // Reads a currents file, one current per line:
+    //   <spot or *>,uniform,<x>,<y>
+    //   <spot or *>,stream,<start x>,<start y>,<end x>,<end y>,<width>,<speed>
+    //   <spot or *>,eddy,<x>,<y>,<radius>,<strength>
+    //   <spot or *>,rip,<x>,<y>,<dir x>,<dir y>,<width>,<length>,<speed>
+    //   <spot or *>,tidal,<flood dir x>,<flood dir y>,<speed>
+    // Blank lines and lines starting with # are skipped.
+    pub fn load(filename: &str) -> Option<Self> {
+        let file = File::open(filename).ok()?;
+        let mut currents = Vec::new();
+
+        for line in BufReader::new(file).lines() {
+            let line = line.ok()?;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match Self::parse_current(line) {
+                Some(current) => currents.push(current),
+                None => println!("Skipping bad current in {}: {}", filename, line),
+            }
+        }
+
+        Some(Self { currents })
+    }
+
+    fn parse_current(line: &str) -> Option<Current> {
+        let mut data = line.split(",").map(|field| field.trim());
+
+        let local = match data.next()? {
+            "*" => None,
+            "Pond1" => Some(FishingLocal::Pond1),
+            "Pond2" => Some(FishingLocal::Pond2),
+            "Ocean" => Some(FishingLocal::Ocean),
+            _ => return None,
+        };
+        let kind = data.next()?;
+        let values: Vec<f32> = data.map(|value| value.parse().ok()).collect::<Option<Vec<f32>>>()?;
+
+        let shape = match (kind, values.as_slice()) {
+            ("uniform", &[x, y]) => CurrentShape::Uniform(Vec3::new(x, y, 0.)),
+            ("stream", &[x1, y1, x2, y2, width, speed]) => CurrentShape::Stream {
+                start: Vec2::new(x1, y1),
+                end: Vec2::new(x2, y2),
+                width,
+                speed,
+            },
+            ("eddy", &[x, y, radius, strength]) => CurrentShape::Eddy {
+                center: Vec2::new(x, y),
+                radius,
+                strength,
+            },
+            ("rip", &[x, y, dx, dy, width, length, speed]) => CurrentShape::Rip {
+                origin: Vec2::new(x, y),
+                direction: Vec2::new(dx, dy),
+                width,
+                length,
+                speed,
+            },
+            ("tidal", &[dx, dy, speed]) => CurrentShape::Tidal {
+                direction: Vec2::new(dx, dy),
+                speed,
+            },
+            _ => return None,
+        };
+
+        Some(Current { local, shape })
+    }
+}
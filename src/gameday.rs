@@ -1,5 +1,19 @@
 use bevy::prelude::*;
 use crate::interface::CurrentInterface;
+use std::time::Duration;
+
+const PAUSE: KeyCode = KeyCode::Space;
+const CYCLE_TIME_SCALE: KeyCode = KeyCode::KeyT;
+
+pub const MINUTES_PER_HOUR: u32 = 60;
+pub const MINUTES_PER_DAY: u32 = 24 * MINUTES_PER_HOUR;
+pub const DAYS_PER_SEASON: u32 = 7;
+pub const DAYS_PER_LUNAR_CYCLE: u32 = 8;
+
+// Moonlight only matters while it's dark out
+pub const NIGHT: TimeWindows = TimeWindows(&[TimeWindow::new(20., 6.)]);
+// Moon at least this lit counts as a bright moon
+const BRIGHT_MOON: f32 = 0.75;
 
 #[derive(Component, PartialEq)]
 pub enum TimePeriod{
@@ -8,10 +22,104 @@ pub enum TimePeriod{
     Night,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Season {
+    Spring,
+    Summer,
+    Autumn,
+    Winter,
+}
+
+impl Season {
+    pub fn is_cold(&self) -> bool {
+        *self == Season::Winter
+    }
+}
+
+// One phase per day of the lunar cycle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MoonPhase {
+    New,
+    WaxingCrescent,
+    FirstQuarter,
+    WaxingGibbous,
+    Full,
+    WaningGibbous,
+    LastQuarter,
+    WaningCrescent,
+}
+
+impl MoonPhase {
+    pub fn from_day(day: u32) -> Self {
+        match day % DAYS_PER_LUNAR_CYCLE {
+            0 => MoonPhase::New,
+            1 => MoonPhase::WaxingCrescent,
+            2 => MoonPhase::FirstQuarter,
+            3 => MoonPhase::WaxingGibbous,
+            4 => MoonPhase::Full,
+            5 => MoonPhase::WaningGibbous,
+            6 => MoonPhase::LastQuarter,
+            _ => MoonPhase::WaningCrescent,
+        }
+    }
+
+    // How much of the moon is lit, 0 at new moon and 1 at full moon
+    pub fn illumination(&self) -> f32 {
+        let cycle = *self as u32 as f32 / DAYS_PER_LUNAR_CYCLE as f32;
+        (1. - f32::cos(2. * std::f32::consts::PI * cycle)) / 2.
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MoonPhase::New => "New Moon",
+            MoonPhase::WaxingCrescent => "Waxing Crescent",
+            MoonPhase::FirstQuarter => "First Quarter",
+            MoonPhase::WaxingGibbous => "Waxing Gibbous",
+            MoonPhase::Full => "Full Moon",
+            MoonPhase::WaningGibbous => "Waning Gibbous",
+            MoonPhase::LastQuarter => "Last Quarter",
+            MoonPhase::WaningCrescent => "Waning Crescent",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeScale {
+    #[default]
+    Normal,
+    Double,
+    Quadruple,
+}
+
+impl TimeScale {
+    pub fn multiplier(&self) -> f32 {
+        match self {
+            TimeScale::Normal => 1.,
+            TimeScale::Double => 2.,
+            TimeScale::Quadruple => 4.,
+        }
+    }
+
+    pub fn next(&self) -> TimeScale {
+        match self {
+            TimeScale::Normal => TimeScale::Double,
+            TimeScale::Double => TimeScale::Quadruple,
+            TimeScale::Quadruple => TimeScale::Normal,
+        }
+    }
+}
+
+// Shared game clock. Everything that cares about the time of day should go
+// through the accessors here rather than keeping its own copy of the hour.
 #[derive(Resource)]
 pub struct GameDayTimer {
-    pub timer: Timer,
-    pub hour: i32,
+    timer: Timer,
+    minutes: u32,
+    day: u32,
+    paused: bool,
+    scale: TimeScale,
+    delta: Duration,
+    hour_changed: bool,
 }
 
 #[derive(States, Default, Debug, Clone, PartialEq, Eq, Hash)]
@@ -23,12 +131,161 @@ pub enum MidnightState {
 
 
 impl GameDayTimer {
+    // duration is the number of real seconds per game hour at normal speed
     pub fn new(duration: f32) -> Self {
         Self {
-            timer: Timer::from_seconds(duration, TimerMode::Repeating),
-            hour: 0,
+            timer: Timer::from_seconds(duration / MINUTES_PER_HOUR as f32, TimerMode::Repeating),
+            minutes: 0,
+            day: 0,
+            paused: false,
+            scale: TimeScale::Normal,
+            delta: Duration::ZERO,
+            hour_changed: false,
         }
-    }    
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.minutes / MINUTES_PER_HOUR
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.minutes % MINUTES_PER_HOUR
+    }
+
+    // Minutes since midnight
+    pub fn minutes(&self) -> u32 {
+        self.minutes
+    }
+
+    // Fractional hour including progress towards the next minute, for anything
+    // that needs to change smoothly over the day
+    pub fn hours(&self) -> f32 {
+        (self.minutes as f32 + self.timer.fraction()) / MINUTES_PER_HOUR as f32
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    // Hours since the game started, for remembering how long ago something was
+    pub fn total_hours(&self) -> f32 {
+        self.day as f32 * 24. + self.hours()
+    }
+
+    pub fn season(&self) -> Season {
+        match (self.day / DAYS_PER_SEASON) % 4 {
+            0 => Season::Spring,
+            1 => Season::Summer,
+            2 => Season::Autumn,
+            _ => Season::Winter,
+        }
+    }
+
+    pub fn moon_phase(&self) -> MoonPhase {
+        MoonPhase::from_day(self.day)
+    }
+
+    pub fn is_night(&self) -> bool {
+        self.is_within(&NIGHT)
+    }
+
+    // A bright moon is up, evidence for the hunger and hook CPTs
+    pub fn bright_moon(&self) -> bool {
+        self.is_night() && self.moon_phase().illumination() >= BRIGHT_MOON
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    pub fn scale(&self) -> TimeScale {
+        self.scale
+    }
+
+    pub fn set_scale(&mut self, scale: TimeScale) {
+        self.scale = scale;
+    }
+
+    // Real time elapsed this frame scaled by the time speed, zero while paused.
+    // Anything that runs on game time (weather, ageing) should tick with this.
+    pub fn delta(&self) -> Duration {
+        self.delta
+    }
+
+    // Game hours that passed this frame
+    pub fn delta_hours(&self) -> f32 {
+        self.delta.as_secs_f32() / (self.timer.duration().as_secs_f32() * MINUTES_PER_HOUR as f32)
+    }
+
+    pub fn hour_just_changed(&self) -> bool {
+        self.hour_changed
+    }
+
+    pub fn is_within(&self, windows: &TimeWindows) -> bool {
+        windows.contains(self.hours())
+    }
+}
+
+// Span of the day in hours, start inclusive and end exclusive. A window whose
+// end is before its start wraps past midnight, e.g. 22 -> 4.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeWindow {
+    pub start: f32,
+    pub end: f32,
+}
+
+impl TimeWindow {
+    pub const fn new(start: f32, end: f32) -> Self {
+        Self { start, end }
+    }
+
+    pub fn contains(&self, hour: f32) -> bool {
+        if self.start <= self.end {
+            hour >= self.start && hour < self.end
+        } else {
+            hour >= self.start || hour < self.end
+        }
+    }
+
+    pub fn length(&self) -> f32 {
+        if self.start <= self.end {
+            self.end - self.start
+        } else {
+            24. - self.start + self.end
+        }
+    }
+}
+
+// One or more windows, e.g. a dawn bite and a dusk bite
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimeWindows(pub &'static [TimeWindow]);
+
+impl TimeWindows {
+    pub fn contains(&self, hour: f32) -> bool {
+        self.0.iter().any(|window| window.contains(hour))
+    }
+
+    // Total hours covered, assumes the windows don't overlap
+    pub fn hours(&self) -> f32 {
+        self.0.iter().map(|window| window.length()).sum::<f32>().min(24.)
+    }
+
+    // Hours these windows share with other, checked on the half hour
+    pub fn overlap(&self, other: &TimeWindows) -> f32 {
+        (0..24)
+            .map(|hour| hour as f32 + 0.5)
+            .filter(|hour| self.contains(*hour) && other.contains(*hour))
+            .count() as f32
+    }
+
+    // Mostly feeds after dark
+    pub fn is_nocturnal(&self) -> bool {
+        self.overlap(&NIGHT) > self.hours() / 2.
+    }
 }
 
 #[derive(Component)]
@@ -36,17 +293,49 @@ pub struct DayTintOverlay;
 
 pub const TIME_PER_PERIOD: f32 = 10.;
 
+pub fn time_controls(
+    input: Res<ButtonInput<KeyCode>>,
+    mut game_timer: ResMut<GameDayTimer>,
+) {
+    if input.just_pressed(PAUSE) {
+        game_timer.toggle_pause();
+        println!("Game time paused: {}", game_timer.is_paused());
+    }
+
+    if input.just_pressed(CYCLE_TIME_SCALE) {
+        let next_scale = game_timer.scale().next();
+        game_timer.set_scale(next_scale);
+        println!("Game time scale: x{}", next_scale.multiplier());
+    }
+}
+
 pub fn run_game_timer(
     time: Res<Time>, 
     mut game_timer: ResMut<GameDayTimer>,
     mut next_state: ResMut<NextState<MidnightState>>,
 )
 {
-    game_timer.timer.tick(time.delta());
-    if game_timer.timer.just_finished() {
-        game_timer.hour = (game_timer.hour + 1) % 24;
-        println!("Hour {}.", game_timer.hour);
-        if game_timer.hour == 23{
+    game_timer.hour_changed = false;
+
+    if game_timer.paused {
+        game_timer.delta = Duration::ZERO;
+        return;
+    }
+
+    game_timer.delta = time.delta().mul_f32(game_timer.scale.multiplier());
+    let delta = game_timer.delta;
+    game_timer.timer.tick(delta);
+
+    let previous_hour = game_timer.hour();
+    let elapsed_minutes = game_timer.timer.times_finished_this_tick();
+    let total_minutes = game_timer.minutes + elapsed_minutes;
+    game_timer.day += total_minutes / MINUTES_PER_DAY;
+    game_timer.minutes = total_minutes % MINUTES_PER_DAY;
+
+    if game_timer.hour() != previous_hour {
+        game_timer.hour_changed = true;
+        println!("Hour {}.", game_timer.hour());
+        if game_timer.hour() == 23{
             println!("entering midnight state");
             next_state.set(MidnightState::Midnight);
 
@@ -59,6 +348,35 @@ pub fn run_game_timer(
     
 }
 
+// Overlay colour through the day as (hour, colour). The first and last
+// entries match so the curve wraps cleanly at midnight.
+const DAY_TINT_KEYS: [(f32, Srgba); 8] = [
+    (0., Srgba::new(0.05, 0.05, 0.25, 0.7)),
+    (4.5, Srgba::new(0.05, 0.05, 0.25, 0.7)),
+    (6., Srgba::new(0.9, 0.5, 0.2, 0.3)),
+    (8., Srgba::new(0.5, 0.5, 0.5, 0.0)),
+    (17., Srgba::new(0.5, 0.5, 0.5, 0.0)),
+    (19., Srgba::new(0.45, 0.2, 0.5, 0.4)),
+    (21., Srgba::new(0.05, 0.05, 0.25, 0.7)),
+    (24., Srgba::new(0.05, 0.05, 0.25, 0.7)),
+];
+
+pub fn day_tint_color(hours: f32) -> Srgba {
+    let hours = hours.rem_euclid(24.);
+
+    for keys in DAY_TINT_KEYS.windows(2) {
+        let (start_hour, start_color) = keys[0];
+        let (end_hour, end_color) = keys[1];
+
+        if hours >= start_hour && hours <= end_hour {
+            let t = (hours - start_hour) / (end_hour - start_hour);
+            return start_color.mix(&end_color, t);
+        }
+    }
+
+    DAY_TINT_KEYS[0].1
+}
+
 pub fn day_tint(
     timer: Res<GameDayTimer>,
     current_interface: Res<State<CurrentInterface>>,
@@ -69,15 +387,7 @@ pub fn day_tint(
             sprite.color = Color::srgba(0.5, 0.5, 0.5, 0.0);
         }
         else{
-            if timer.hour > 5 && timer.hour < 19 {
-                sprite.color = Color::srgba(0.5, 0.5, 0.5, 0.0);
-            }
-            else if timer.hour == 5 || timer.hour == 19{
-                sprite.color = Color::srgba(0.1, 0.1, 0.3, 0.5);
-            }
-            else {
-                sprite.color = Color::srgba(0.1, 0.1, 0.3, 0.7);
-            }
+            sprite.color = day_tint_color(timer.hours()).into();
         }
     }
 }
@@ -95,3 +405,35 @@ pub fn spawn_day_tint_overlay(mut commands: Commands){
     DayTintOverlay
     ));
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn window_wraps_past_midnight() {
+        let window = TimeWindow::new(18., 1.);
+
+        assert!(window.contains(18.));
+        assert!(window.contains(23.5));
+        assert!(window.contains(0.));
+        assert!(window.contains(0.5));
+        assert!(!window.contains(1.));
+        assert!(!window.contains(12.));
+        assert_eq!(window.length(), 7.);
+    }
+
+    #[test]
+    fn window_ending_at_midnight() {
+        let window = TimeWindow::new(20., 24.);
+
+        assert!(window.contains(23.9));
+        assert!(!window.contains(0.));
+        assert_eq!(window.length(), 4.);
+
+        let window = TimeWindow::new(0., 23.);
+        assert!(window.contains(0.));
+        assert!(!window.contains(23.));
+        assert_eq!(window.length(), 23.);
+    }
+}
@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+use rand::Rng;
+use std::collections::HashMap;
+
+use crate::fish::*;
+use crate::fishing_view::*;
+use crate::gameday::*;
+use crate::physics::*;
+use crate::species::*;
+
+// Sweep the rod right after the bite to drive the hook home
+const SET_HOOK: KeyCode = KeyCode::KeyP;
+
+// How far the hook goes in by itself when a fish bites, least and most
+const BITE_SET_MIN: f32 = 0.2;
+const BITE_SET_MAX: f32 = 0.5;
+// Seconds after the bite a sweep still helps, the quicker the better
+const HOOKSET_WINDOW: f32 = 0.8;
+// Seconds a barely set hook stays in on a slack line, a solid one lasts twice as long
+const SLACK_HOLD_TIME: f32 = 1.5;
+// Seconds of pulling harder than the mouth can take before the hook tears out
+const TEAR_HOLD_TIME: f32 = 0.75;
+// Chance per second a hook that's hardly in just works loose
+const LOOSE_HOOK_CHANCE: f32 = 0.1;
+// Game hours a fish that got off stays wary of lures
+const HOOK_SHY_HOURS: f32 = 6.;
+
+// How well the hook is holding in a fish's mouth
+#[derive(Component)]
+pub struct HookHold {
+    // 0 for barely in, 1 for solidly set
+    pub set: f32,
+    pub age: f32,
+    pub swept: bool,
+    pub slack: f32,
+    pub strain: f32,
+}
+
+impl HookHold {
+    // A fish just bit, the hook goes in part way by itself
+    pub fn on_bite() -> Self {
+        Self {
+            set: rand::thread_rng().gen_range(BITE_SET_MIN..BITE_SET_MAX),
+            age: 0.,
+            swept: false,
+            slack: 0.,
+            strain: 0.,
+        }
+    }
+
+    fn hold_time(&self, time: f32) -> f32 {
+        time * (1. + self.set)
+    }
+}
+
+// Fish that got off the hook lately, by fish id, and the game hour they get over it
+#[derive(Resource, Default)]
+pub struct HookShy {
+    until: HashMap<u32, f32>,
+}
+
+impl HookShy {
+    pub fn remember(&mut self, fish_id: u32, now: f32) {
+        self.until.insert(fish_id, now + HOOK_SHY_HOURS);
+    }
+
+    // 1 right after it got away, fading to 0 as it forgets
+    pub fn shyness(&self, fish_id: u32, now: f32) -> f32 {
+        self.until
+            .get(&fish_id)
+            .map_or(0., |until| ((until - now) / HOOK_SHY_HOURS).clamp(0., 1.))
+    }
+}
+
+pub fn hold_hook(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut next_state: ResMut<NextState<FishingState>>,
+    line: Query<&FishingLine>,
+    mut fish: Query<(Entity, &mut PhysicsObject, &Species, &mut HookHold), (With<Fish>, With<Hooked>)>,
+) {
+    let Ok((entity_id, mut fish_physics, species, mut hold)) = fish.get_single_mut() else {
+        return;
+    };
+
+    let dt = time.delta_seconds();
+    hold.age += dt;
+
+    // Quick sweep after the bite
+    if !hold.swept && hold.age < HOOKSET_WINDOW && input.just_pressed(SET_HOOK) {
+        hold.swept = true;
+        hold.set = (hold.set + 1. - hold.age / HOOKSET_WINDOW).min(1.);
+        println!("Set the hook! {:.0}% in", hold.set * 100.);
+    }
+
+    let tension = line_tension(&fish_physics, line.single());
+    hold.slack = if tension < SLACK_TENSION { hold.slack + dt } else { 0. };
+    hold.strain = if tension > species.mouth_strength {
+        hold.strain + dt
+    } else {
+        (hold.strain - dt).max(0.)
+    };
+
+    let lost = if hold.slack > hold.hold_time(SLACK_HOLD_TIME) {
+        Some("The line went slack and the fish shook the hook")
+    } else if hold.strain > hold.hold_time(TEAR_HOLD_TIME) {
+        Some("Pulled too hard and the hook tore out of its mouth")
+    } else if rand::thread_rng().gen::<f32>() < (1. - hold.set) * LOOSE_HOOK_CHANCE * dt {
+        Some("The hook was never set properly and it worked loose")
+    } else {
+        None
+    };
+
+    if let Some(message) = lost {
+        println!("{}", message);
+        commands.entity(entity_id).remove::<Hooked>();
+        fish_physics.forces.player = Vec3::ZERO;
+        next_state.set(FishingState::Idle);
+    }
+}
+
+// Caught fish are gone by now, anything still holding a hook got away
+pub fn remember_lost_fish(
+    mut commands: Commands,
+    timer: Res<GameDayTimer>,
+    mut hook_shy: ResMut<HookShy>,
+    lost: Query<(Entity, &Fish, &Species), With<HookHold>>,
+) {
+    for (entity_id, fish, species) in lost.iter() {
+        println!("The {} got away, it'll be hook shy for a while", species.name);
+        hook_shy.remember(fish.id, timer.total_hours());
+        commands.entity(entity_id).remove::<HookHold>();
+    }
+}
@@ -3,6 +3,10 @@ use crate::gameday::*;
 use crate::inventory::*;
 use crate::weather::*;
 use crate::interface::*;
+use crate::lightning::*;
+use crate::fishing_view::*;
+use crate::physics::*;
+use crate::water_temperature::*;
 
 #[derive(Component)]
 pub struct MoneyDisplay;
@@ -16,6 +20,21 @@ pub struct WeatherDisplay;
 #[derive(Component)]
 pub struct HintDisplay;
 
+#[derive(Component)]
+pub struct StrikeRiskDisplay;
+
+#[derive(Component)]
+pub struct WindDisplay;
+
+#[derive(Component)]
+pub struct MoonDisplay;
+
+#[derive(Component)]
+pub struct WaterTempDisplay;
+
+#[derive(Component)]
+pub struct BarometerDisplay;
+
 pub fn spawn_money_display(commands: &mut Commands, asset_server: &Res<AssetServer>) {
     commands.spawn((
         TextBundle::from_section(
@@ -39,7 +58,7 @@ pub fn spawn_money_display(commands: &mut Commands, asset_server: &Res<AssetServ
 pub fn spawn_clock_display(commands: &mut Commands, asset_server: &Res<AssetServer>) {
     commands.spawn((
         TextBundle::from_section(
-            "Time: 0",
+            "Time: 00:00",
             TextStyle {
                 font: asset_server.load("fonts/pixel.ttf"),
                 font_size: 65.0,
@@ -76,6 +95,122 @@ pub fn spawn_weather_display(commands: &mut Commands, asset_server: &Res<AssetSe
     ));
 }
 
+pub fn spawn_wind_display(commands: &mut Commands, asset_server: &Res<AssetServer>) {
+    commands.spawn((
+        TextBundle::from_section(
+            "Wind: 0",
+            TextStyle {
+                font: asset_server.load("fonts/pixel.ttf"),
+                font_size: 50.0,
+                color: Color::srgb(0.0, 0.0, 0.0),
+            },
+        )
+        .with_style(Style {
+            position_type: PositionType::Absolute,
+            top: Val::Px(150.0),
+            left: Val::Px(5.0),
+            ..default()
+        }),
+        WindDisplay,
+    ));
+}
+
+pub fn spawn_strike_risk_display(commands: &mut Commands, asset_server: &Res<AssetServer>) {
+    let mut text = TextBundle::from_section(
+        "Strike risk: 0%",
+        TextStyle {
+            font: asset_server.load("fonts/pixel.ttf"),
+            font_size: 50.0,
+            color: Color::srgb(0.9, 0.8, 0.1),
+        },
+    )
+    .with_style(Style {
+        position_type: PositionType::Absolute,
+        top: Val::Px(250.0),
+        left: Val::Px(5.0),
+        ..default()
+    });
+
+    text.visibility = Visibility::Hidden;
+
+    commands.spawn((
+        text,
+        StrikeRiskDisplay,
+    ));
+}
+
+pub fn spawn_moon_display(commands: &mut Commands, asset_server: &Res<AssetServer>) {
+    let mut text = TextBundle::from_section(
+        "Moon: New Moon",
+        TextStyle {
+            font: asset_server.load("fonts/pixel.ttf"),
+            font_size: 50.0,
+            color: Color::srgb(0.85, 0.85, 1.0),
+        },
+    )
+    .with_style(Style {
+        position_type: PositionType::Absolute,
+        top: Val::Px(200.0),
+        left: Val::Px(5.0),
+        ..default()
+    });
+
+    text.visibility = Visibility::Hidden;
+
+    commands.spawn((
+        text,
+        MoonDisplay,
+    ));
+}
+
+pub fn spawn_water_temp_display(commands: &mut Commands, asset_server: &Res<AssetServer>) {
+    let mut text = TextBundle::from_section(
+        "Water: 0.0C",
+        TextStyle {
+            font: asset_server.load("fonts/pixel.ttf"),
+            font_size: 50.0,
+            color: Color::srgb(0.1, 0.3, 0.8),
+        },
+    )
+    .with_style(Style {
+        position_type: PositionType::Absolute,
+        top: Val::Px(300.0),
+        left: Val::Px(5.0),
+        ..default()
+    });
+
+    text.visibility = Visibility::Hidden;
+
+    commands.spawn((
+        text,
+        WaterTempDisplay,
+    ));
+}
+
+pub fn spawn_barometer_display(commands: &mut Commands, asset_server: &Res<AssetServer>) {
+    let mut text = TextBundle::from_section(
+        "Pressure: 0 hPa",
+        TextStyle {
+            font: asset_server.load("fonts/pixel.ttf"),
+            font_size: 50.0,
+            color: Color::srgb(0.0, 0.0, 0.0),
+        },
+    )
+    .with_style(Style {
+        position_type: PositionType::Absolute,
+        top: Val::Px(350.0),
+        left: Val::Px(5.0),
+        ..default()
+    });
+
+    text.visibility = Visibility::Hidden;
+
+    commands.spawn((
+        text,
+        BarometerDisplay,
+    ));
+}
+
 pub fn spawn_hint(commands: &mut Commands, asset_server: &Res<AssetServer>) {
     let mut text =         TextBundle::from_section(
         "Ocean fishing requires the surf rod",
@@ -116,7 +251,14 @@ pub fn update_clock_display(
     interface: Res<State<CurrentInterface>>,
 ) {
     let (mut text, mut visibility) = query.single_mut();
-    text.sections[0].value = format!("Hour: {}", time.hour);
+    let speed = if time.is_paused() {
+        " (Paused)".to_string()
+    } else if time.scale() != TimeScale::Normal {
+        format!(" x{}", time.scale().multiplier())
+    } else {
+        String::new()
+    };
+    text.sections[0].value = format!("Time: {:02}:{:02}{}", time.hour(), time.minute(), speed);
     if interface.eq(&CurrentInterface::Shop) {
         *visibility = Visibility::Hidden;
     }
@@ -149,7 +291,123 @@ pub fn update_weather_display(
             Weather::Rainy => "Rainy",
             Weather::Thunderstorm => "Thunderstorm",
             Weather::Sunny=> "Sunny",
+            Weather::Fog => "Fog",
+            Weather::Snow => "Snow",
         };
         text.sections[0].value = format!("Region: {} | Weather: {}", region_name, weather_description);
     }
-}
\ No newline at end of file
+}
+pub fn update_strike_risk_display(
+    risk: Res<StrikeRisk>,
+    mut query: Query<(&mut Text, &mut Visibility), With<StrikeRiskDisplay>>,
+    interface: Res<State<CurrentInterface>>,
+) {
+    let (mut text, mut visibility) = query.single_mut();
+    if !interface.eq(&CurrentInterface::Fishing) || risk.0 <= 0. {
+        *visibility = Visibility::Hidden;
+    }
+    else {
+        *visibility = Visibility::Visible;
+        text.sections[0].value = format!("Strike risk: {:.0}%", risk.0 * 100.);
+    }
+}
+
+pub fn update_wind_display(
+    wind: Res<WindState>,
+    current_region: Res<State<Region>>,
+    mut query: Query<(&mut Text, &mut Visibility), With<WindDisplay>>,
+    interface: Res<State<CurrentInterface>>,
+) {
+    let (mut text, mut visibility) = query.single_mut();
+    if interface.eq(&CurrentInterface::Shop) {
+        *visibility = Visibility::Hidden;
+    }
+    else {
+        *visibility = Visibility::Visible;
+        let wind_velocity = wind.get(current_region.get());
+
+        // Compass point the wind is blowing from
+        let compass = ["E", "NE", "N", "NW", "W", "SW", "S", "SE"];
+        let from = (-wind_velocity).to_angle().rem_euclid(2. * std::f32::consts::PI);
+        let point = ((from / (std::f32::consts::PI / 4.)).round() as usize) % 8;
+
+        text.sections[0].value = format!("Wind: {:.0} from {}", wind_velocity.length(), compass[point]);
+    }
+}
+
+pub fn update_moon_display(
+    time: Res<GameDayTimer>,
+    mut query: Query<(&mut Text, &mut Visibility), With<MoonDisplay>>,
+    interface: Res<State<CurrentInterface>>,
+) {
+    let (mut text, mut visibility) = query.single_mut();
+    // Only visible at night
+    if interface.eq(&CurrentInterface::Shop) || !time.is_night() {
+        *visibility = Visibility::Hidden;
+    }
+    else {
+        *visibility = Visibility::Visible;
+        text.sections[0].value = format!("Moon: {}", time.moon_phase().name());
+    }
+}
+
+// Thermometer reading, needs the thermometer from the shop
+pub fn update_water_temp_display(
+    water: Res<WaterTemperature>,
+    fishing_location: Res<State<FishingLocal>>,
+    player_inventory: Query<&PlayerInventory>,
+    lure: Query<&PhysicsObject, With<Lure>>,
+    mut query: Query<(&mut Text, &mut Visibility), With<WaterTempDisplay>>,
+    interface: Res<State<CurrentInterface>>,
+) {
+    let (mut text, mut visibility) = query.single_mut();
+    let has_thermometer = player_inventory
+        .get_single()
+        .is_ok_and(|inventory| inventory.has_tool("Thermometer"));
+
+    if !interface.eq(&CurrentInterface::Fishing) || !has_thermometer {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    *visibility = Visibility::Visible;
+    let local = fishing_location.get();
+    let surface = water.surface(local);
+
+    text.sections[0].value = match lure.get_single() {
+        Ok(lure_physics) => {
+            let lure_depth = lure_physics.depth_meters();
+            format!("Water: {:.1}C, {:.1}C at lure", surface, water.at(local, lure_depth))
+        }
+        Err(_) => format!("Water: {:.1}C", surface),
+    };
+}
+
+// Barometer reading, needs the barometer from the shop
+pub fn update_barometer_display(
+    pressure: Res<PressureState>,
+    current_region: Res<State<Region>>,
+    player_inventory: Query<&PlayerInventory>,
+    mut query: Query<(&mut Text, &mut Visibility), With<BarometerDisplay>>,
+    interface: Res<State<CurrentInterface>>,
+) {
+    let (mut text, mut visibility) = query.single_mut();
+    let has_barometer = player_inventory
+        .get_single()
+        .is_ok_and(|inventory| inventory.has_tool("Barometer"));
+
+    if interface.eq(&CurrentInterface::Shop) || !has_barometer {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    *visibility = Visibility::Visible;
+    let region = current_region.get();
+    let trend = match pressure.trend(region) {
+        PressureTrend::Rising => "Rising",
+        PressureTrend::Steady => "Steady",
+        PressureTrend::Falling => "Falling",
+    };
+
+    text.sections[0].value = format!("Pressure: {:.0} hPa {}", pressure.get(region), trend);
+}
@@ -1,6 +1,13 @@
 use bevy::prelude::*;
 use crate::shop::*;
 
+// Something reeled in that isn't a fish, the shop buys it off you
+#[derive(Clone)]
+pub struct JunkItem {
+    pub name: String,
+    pub value: u32,
+}
+
 #[derive(Component)]
 pub struct PlayerInventory {
     pub coins: u32,
@@ -9,6 +16,8 @@ pub struct PlayerInventory {
     pub lures: Vec<ShopItem>,
     pub lines: Vec<ShopItem>,
     pub cosmetics: Vec<ShopItem>,
+    pub tools: Vec<ShopItem>,
+    pub junk: Vec<JunkItem>,
     pub rod_index: usize,
     pub lure_index: usize,
     pub line_index: usize,
@@ -39,4 +48,32 @@ pub fn handle_inventory (
     for cosmetic in inventory.cosmetics.iter() {
         println!("cosmetics: {}", cosmetic.name);
     }
+
+    for tool in inventory.tools.iter() {
+        println!("tools: {}", tool.name);
+    }
+
+    for junk in inventory.junk.iter() {
+        println!("junk: {}", junk.name);
+    }
+}
+
+impl PlayerInventory {
+    pub fn add_item(&mut self, item: &ShopItem) {
+        self.items.push(item.clone());
+
+        let category = match item.item_type {
+            ItemType::ROD => &mut self.rods,
+            ItemType::LURE => &mut self.lures,
+            ItemType::LINE => &mut self.lines,
+            ItemType::COSMETIC => &mut self.cosmetics,
+            ItemType::TOOL => &mut self.tools,
+        };
+
+        category.push(item.clone());
+    }
+
+    pub fn has_tool(&self, name: &str) -> bool {
+        self.tools.iter().any(|tool| tool.name == name)
+    }
 }
\ No newline at end of file
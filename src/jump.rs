@@ -0,0 +1,127 @@
+use bevy::prelude::*;
+use rand::Rng;
+use std::f32::consts::PI;
+
+use crate::fish::*;
+use crate::fishing_view::*;
+use crate::physics::*;
+use crate::species::*;
+
+// Held to drop the rod tip and bow to a jumping fish
+const BOW: KeyCode = KeyCode::KeyS;
+
+// Fish only breach from this close to the surface, in metres
+const BREACH_DEPTH: f32 = 4.;
+// Upward speed leaving the water, metres per second
+const JUMP_SPEED: f32 = 6.;
+// Seconds between head shakes in the air
+const SHAKE_TIME: f32 = 0.25;
+// How far the head swings either way on a shake, radians
+const SHAKE_ANGLE: f32 = 0.5;
+// Chance each head shake throws the hook, with a slack line and with the rod held high
+const SLACK_THROW_CHANCE: f32 = 0.1;
+const HIGH_ROD_THROW_CHANCE: f32 = 0.05;
+
+// Chance per second a hooked fish near the surface jumps
+fn jump_chance(behavior: Behavior) -> f32 {
+    match behavior {
+        Behavior::Aggressive => 0.25,
+        Behavior::Elusive => 0.35,
+        Behavior::Evasive => 0.08,
+        Behavior::Passive => 0.,
+    }
+}
+
+// Hooked fish in the air, shaking its head to throw the hook
+#[derive(Component, Default)]
+pub struct Airborne {
+    pub time: f32,
+    pub shakes: u32,
+}
+
+fn splash_at(splash: &mut Query<(&mut Splash, &mut Visibility, &mut TextureAtlas)>, position: Vec3) {
+    let Ok((mut splash_info, mut splash_visibility, mut splash_texture)) = splash.get_single_mut() else {
+        return;
+    };
+
+    splash_info.position = position.with_z(902.);
+    splash_texture.index = 0;
+    *splash_visibility = Visibility::Visible;
+}
+
+pub fn fish_jump(
+    mut commands: Commands,
+    time: Res<Time>,
+    mut splash: Query<(&mut Splash, &mut Visibility, &mut TextureAtlas)>,
+    mut fish: Query<(Entity, &mut PhysicsObject, &Species), (With<Fish>, With<Hooked>, Without<Airborne>)>,
+) {
+    let Ok((entity_id, mut fish_physics, species)) = fish.get_single_mut() else {
+        return;
+    };
+
+    if fish_physics.depth_meters() > BREACH_DEPTH
+        || rand::thread_rng().gen::<f32>() >= jump_chance(species.behavior) * time.delta_seconds()
+    {
+        return;
+    }
+
+    println!("The {} jumped! Hold S to bow the rod", species.name);
+    fish_physics.position.z = 0.;
+    fish_physics.velocity.z = JUMP_SPEED * DEPTH_UNITS_PER_METER;
+    commands.entity(entity_id).insert(Airborne::default());
+    splash_at(&mut splash, fish_physics.position);
+}
+
+pub fn fish_airborne(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    mut next_state: ResMut<NextState<FishingState>>,
+    line: Query<&FishingLine>,
+    mut splash: Query<(&mut Splash, &mut Visibility, &mut TextureAtlas)>,
+    mut fish: Query<(Entity, &mut PhysicsObject, &mut Airborne), (With<Fish>, With<Hooked>)>,
+) {
+    let Ok((entity_id, mut fish_physics, mut airborne)) = fish.get_single_mut() else {
+        return;
+    };
+
+    // Back in the water
+    if fish_physics.position.z <= 0. && fish_physics.velocity.z <= 0. {
+        commands.entity(entity_id).remove::<Airborne>();
+        splash_at(&mut splash, fish_physics.position);
+        return;
+    }
+
+    airborne.time += time.delta_seconds();
+
+    // Thrash the head from side to side, spinning one way then the other
+    fish_physics.angular_velocity.z = SHAKE_ANGLE * PI / SHAKE_TIME * (airborne.time / SHAKE_TIME * PI).cos();
+
+    if airborne.time < (airborne.shakes + 1) as f32 * SHAKE_TIME {
+        return;
+    }
+    airborne.shakes += 1;
+
+    // A tight line and a lowered rod keep the hook set through the shakes
+    let mut throw_chance = 0.;
+    if line_tension(&fish_physics, line.single()) < SLACK_TENSION {
+        throw_chance += SLACK_THROW_CHANCE;
+    }
+    if !input.pressed(BOW) {
+        throw_chance += HIGH_ROD_THROW_CHANCE;
+    }
+
+    if rand::thread_rng().gen::<f32>() < throw_chance {
+        println!("The fish shook its head and threw the hook!");
+        commands.entity(entity_id).remove::<Hooked>();
+        fish_physics.forces.player = Vec3::ZERO;
+        next_state.set(FishingState::Idle);
+    }
+}
+
+// Whatever was in the air drops back in once the fight's over
+pub fn clear_airborne(mut commands: Commands, airborne: Query<Entity, With<Airborne>>) {
+    for entity_id in airborne.iter() {
+        commands.entity(entity_id).remove::<Airborne>();
+    }
+}
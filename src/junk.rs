@@ -0,0 +1,338 @@
+use bevy::prelude::*;
+use rand::Rng;
+use std::fs::File;
+use std::io::{BufRead, BufReader};
+
+use crate::fishing_view::*;
+use crate::inventory::*;
+use crate::map::*;
+use crate::player::*;
+use crate::shop::*;
+use crate::tide::*;
+
+const JUNK_TABLE_FILE: &str = "assets/fishing_view/junk.table";
+
+// Most bits of junk floating around the spot at once
+const MAX_JUNK: usize = 5;
+// Already floating there when you show up
+const STARTING_JUNK: usize = 2;
+// Seconds between new bits of junk washing in
+const JUNK_SPAWN_TIME: f32 = 8.;
+
+// Junk washes in over the open water, clear of the shore and the HUD. It's
+// gone once it drifts out of the view.
+const WATER_MIN: Vec2 = Vec2::new(-560., -150.);
+const WATER_MAX: Vec2 = Vec2::new(460., 320.);
+const VIEW_WIDTH: f32 = 1280.;
+const VIEW_HEIGHT: f32 = 720.;
+
+pub enum JunkContents {
+    Nothing,
+    Coins(u32, u32),
+    Item(String),
+}
+
+// One row of the junk table
+pub struct JunkKind {
+    pub name: String,
+    pub local: Option<FishingLocal>,
+    pub rarity: f32,
+    pub mass: f32,
+    pub drag_increase: f32,
+    pub width: f32,
+    pub height: f32,
+    pub value: u32,
+    pub texture: String,
+    pub contents: JunkContents,
+}
+
+#[derive(Resource)]
+pub struct JunkTable {
+    pub kinds: Vec<JunkKind>,
+    spawn_timer: Timer,
+}
+
+impl Default for JunkTable {
+    fn default() -> Self {
+        let kinds = Self::load(JUNK_TABLE_FILE).unwrap_or_else(|| {
+            println!("Couldn't load {}, no junk in the water", JUNK_TABLE_FILE);
+            Vec::new()
+        });
+
+        Self {
+            kinds,
+            spawn_timer: Timer::from_seconds(JUNK_SPAWN_TIME, TimerMode::Repeating),
+        }
+    }
+}
+
+impl JunkTable {
+    // Reads a junk table, one kind of junk per line:
+    //   <name>,<spot or *>,<rarity>,<mass>,<drag>,<width>,<height>,<value>,<texture>,<contents>
+    // Blank lines and lines starting with # are skipped.
+    pub fn load(filename: &str) -> Option<Vec<JunkKind>> {
+        let file = File::open(filename).ok()?;
+        let mut kinds = Vec::new();
+
+        for line in BufReader::new(file).lines() {
+            let line = line.ok()?;
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            match Self::parse_kind(line) {
+                Some(kind) => kinds.push(kind),
+                None => println!("Skipping bad junk in {}: {}", filename, line),
+            }
+        }
+
+        Some(kinds)
+    }
+
+    fn parse_kind(line: &str) -> Option<JunkKind> {
+        let data: Vec<&str> = line.split(",").map(|field| field.trim()).collect();
+        let &[name, local, rarity, mass, drag, width, height, value, texture, contents] = data.as_slice() else {
+            return None;
+        };
+
+        let local = match local {
+            "*" => None,
+            "Pond1" => Some(FishingLocal::Pond1),
+            "Pond2" => Some(FishingLocal::Pond2),
+            "Ocean" => Some(FishingLocal::Ocean),
+            _ => return None,
+        };
+
+        let contents = match contents.strip_prefix("coins:") {
+            Some(range) => {
+                let (min, max) = range.split_once("-")?;
+                let (min, max) = (min.parse().ok()?, max.parse().ok()?);
+                if min > max {
+                    return None;
+                }
+                JunkContents::Coins(min, max)
+            }
+            None if contents == "nothing" => JunkContents::Nothing,
+            None => JunkContents::Item(contents.to_string()),
+        };
+
+        Some(JunkKind {
+            name: name.to_string(),
+            local,
+            rarity: rarity.parse().ok()?,
+            mass: mass.parse().ok()?,
+            drag_increase: drag.parse().ok()?,
+            width: width.parse().ok()?,
+            height: height.parse().ok()?,
+            value: value.parse().ok()?,
+            texture: texture.to_string(),
+            contents,
+        })
+    }
+
+    // Random kind of junk found at a spot, rarer kinds turn up less often
+    pub fn pick(&self, local: &FishingLocal) -> Option<usize> {
+        let found_here = |kind: &JunkKind| kind.local.as_ref().is_none_or(|l| l == local);
+        let total: f32 = self.kinds.iter().filter(|kind| found_here(kind)).map(|kind| kind.rarity).sum();
+
+        if total <= 0. {
+            return None;
+        }
+
+        let mut roll = rand::thread_rng().gen_range(0. ..total);
+        for (i, kind) in self.kinds.iter().enumerate().filter(|(_, kind)| found_here(kind)) {
+            if roll < kind.rarity {
+                return Some(i);
+            }
+            roll -= kind.rarity;
+        }
+        None
+    }
+}
+
+// Which row of the junk table this is
+#[derive(Component)]
+pub struct Junk(pub usize);
+
+// Junk reeled all the way in, it goes in the inventory next frame
+#[derive(Component)]
+pub struct Landed;
+
+fn spawn_one_junk(commands: &mut Commands, asset_server: &AssetServer, table: &JunkTable, local: &FishingLocal) {
+    let Some(index) = table.pick(local) else {
+        return;
+    };
+
+    let kind = &table.kinds[index];
+    let mut rng = rand::thread_rng();
+    let position = FISHING_ROOM_CENTER
+        + Vec2::new(
+            rng.gen_range(WATER_MIN.x..WATER_MAX.x),
+            rng.gen_range(WATER_MIN.y..WATER_MAX.y),
+        );
+
+    commands.spawn((
+        SpriteBundle {
+            texture: asset_server.load(kind.texture.clone()),
+            sprite: Sprite {
+                custom_size: Some(Vec2::new(kind.width, kind.height)),
+                ..default()
+            },
+            transform: Transform::from_translation(position.extend(901.)),
+            ..default()
+        },
+        PondObstruction,
+        ObstType::Debris,
+        DebrisType::new(kind.mass, kind.drag_increase, kind.width, kind.height),
+        DebrisHooked { hooked: false },
+        InPond,
+        local.clone(),
+        Junk(index),
+    ));
+}
+
+pub fn stock_junk(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    table: Res<JunkTable>,
+    fishing_location: Res<State<FishingLocal>>,
+) {
+    for _ in 0..STARTING_JUNK {
+        spawn_one_junk(&mut commands, &asset_server, &table, fishing_location.get());
+    }
+}
+
+pub fn spawn_junk(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    time: Res<Time>,
+    mut table: ResMut<JunkTable>,
+    fishing_location: Res<State<FishingLocal>>,
+    junk: Query<&Junk>,
+) {
+    if !table.spawn_timer.tick(time.delta()).just_finished() || junk.iter().count() >= MAX_JUNK {
+        return;
+    }
+
+    spawn_one_junk(&mut commands, &asset_server, &table, fishing_location.get());
+}
+
+// Loose junk goes wherever the water takes it
+pub fn drift_junk(
+    mut commands: Commands,
+    time: Res<Time>,
+    map: Res<Map>,
+    tide: Res<Tide>,
+    fishing_location: Res<State<FishingLocal>>,
+    player: Query<&Location, With<Player>>,
+    mut junk: Query<(Entity, &mut Transform, &DebrisHooked), (With<Junk>, Without<Landed>)>,
+) {
+    let player_location = player.single();
+    let zone = &map.areas[player_location.x][player_location.y].zone;
+
+    for (entity_id, mut transform, hooked) in junk.iter_mut() {
+        if hooked.hooked {
+            continue;
+        }
+
+        let current = zone.current_at(fishing_location.get(), transform.translation, &tide);
+        transform.translation += current.with_z(0.) * time.delta_seconds();
+
+        let relative = transform.translation.truncate() - FISHING_ROOM_CENTER;
+        if relative.x.abs() > VIEW_WIDTH / 2. || relative.y.abs() > VIEW_HEIGHT / 2. {
+            commands.entity(entity_id).despawn();
+        }
+    }
+}
+
+// Reeled in junk goes in the inventory, and sometimes there's something inside
+pub fn collect_junk(
+    mut commands: Commands,
+    table: Res<JunkTable>,
+    mut player_inventory: Query<&mut PlayerInventory>,
+    mut shop_items: Query<&mut ShopItem>,
+    landed: Query<(Entity, &Junk), With<Landed>>,
+) {
+    for (entity_id, junk) in landed.iter() {
+        commands.entity(entity_id).despawn();
+
+        let Some(kind) = table.kinds.get(junk.0) else {
+            continue;
+        };
+        let mut inventory = player_inventory.single_mut();
+
+        println!("Reeled in: {}", kind.name);
+        inventory.junk.push(JunkItem {
+            name: kind.name.clone(),
+            value: kind.value,
+        });
+
+        match &kind.contents {
+            JunkContents::Nothing => {}
+            JunkContents::Coins(min, max) => {
+                let coins = rand::thread_rng().gen_range(*min..=*max);
+                inventory.coins += coins;
+                println!("There were {} coins inside!", coins);
+            }
+            JunkContents::Item(name) => match shop_items.iter_mut().find(|item| item.name == name.as_str()) {
+                Some(mut item) if !item.is_bought => {
+                    item.is_bought = true;
+                    inventory.add_item(&item);
+                    println!("Found a {}!", item.name);
+                }
+                _ => println!("It's a {}, but you've already got one", name),
+            },
+        }
+    }
+}
+
+// Junk still on the line when it snaps floats off again
+pub fn drop_junk(mut junk: Query<&mut DebrisHooked, (With<Junk>, Without<Landed>)>) {
+    for mut hooked in junk.iter_mut() {
+        hooked.hooked = false;
+    }
+}
+
+// Leaving the water, whatever's floating about is gone by the next visit
+pub fn clear_junk(mut commands: Commands, junk: Query<Entity, (With<Junk>, Without<Landed>)>) {
+    for entity_id in junk.iter() {
+        commands.entity(entity_id).despawn();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_kind_rejects_bad_lines() {
+        // too few fields
+        assert!(JunkTable::parse_kind("Tin Can,*,25,0.3,0.2,24,24,0").is_none());
+        // unknown spot
+        assert!(JunkTable::parse_kind("Tin Can,Lake,25,0.3,0.2,24,24,0,fishing_view/tin_can.png,nothing").is_none());
+        // rarity isn't a number
+        assert!(JunkTable::parse_kind("Tin Can,*,lots,0.3,0.2,24,24,0,fishing_view/tin_can.png,nothing").is_none());
+        // coins the wrong way round
+        assert!(JunkTable::parse_kind("Chest,Ocean,1,6.0,1.2,48,48,40,fishing_view/treasure_chest.png,coins:40-10").is_none());
+    }
+
+    #[test]
+    fn parse_kind_reads_coins() {
+        let kind = JunkTable::parse_kind("Message in a Bottle,Ocean,3,0.5,0.3,30,42,2,fishing_view/water_bottle.png,coins:10-40").unwrap();
+
+        assert!(matches!(kind.contents, JunkContents::Coins(10, 40)));
+        assert!(kind.local == Some(FishingLocal::Ocean));
+        assert_eq!(kind.value, 2);
+    }
+
+    #[test]
+    fn parse_kind_star_is_any_spot() {
+        let kind = JunkTable::parse_kind("Tin Can,*,25,0.3,0.2,24,24,0,fishing_view/tin_can.png,nothing").unwrap();
+
+        assert!(kind.local.is_none());
+        assert!(matches!(kind.contents, JunkContents::Nothing));
+        assert_eq!(kind.rarity, 25.);
+    }
+}
@@ -1,18 +1,28 @@
+pub mod bottom;
 pub mod button;
 pub mod camera;
+pub mod depth_inset;
 pub mod fish;
 pub mod fishing_view;
 pub mod fishing_zone;
 pub mod gameday;
+pub mod hook_hold;
 pub mod hud;
 pub mod interface;
 pub mod inventory;
+pub mod jump;
+pub mod junk;
+pub mod lightning;
 pub mod map;
 pub mod physics;
 pub mod player;
 pub mod prob_calc;
 pub mod resources;
 pub mod shop;
+pub mod snag;
 pub mod species;
+pub mod tide;
+pub mod water_clarity;
+pub mod water_temperature;
 pub mod weather;
 pub mod window;
\ No newline at end of file
@@ -0,0 +1,158 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::button::{exit_fishing, FishingButton};
+use crate::fishing_view::*;
+use crate::gameday::GameDayTimer;
+use crate::interface::CurrentInterface;
+use crate::physics::*;
+use crate::weather::*;
+
+// Seconds between strikes at normal game speed
+const STRIKE_INTERVAL: (f32, f32) = (4., 12.);
+const FLASH_DECAY: f32 = 4.;
+const FLASH_MAX_ALPHA: f32 = 0.8;
+
+// How fast the strike risk builds while the line is out in a storm, per second
+const RISK_GROWTH: f32 = 0.04;
+const RISK_DECAY: f32 = 0.1;
+
+#[derive(Event)]
+pub struct LightningStrikeEvent;
+
+#[derive(Resource)]
+pub struct LightningState {
+    pub timer: Timer,
+    pub flash: f32,
+}
+
+impl Default for LightningState {
+    fn default() -> Self {
+        Self {
+            timer: Timer::from_seconds(STRIKE_INTERVAL.1, TimerMode::Once),
+            flash: 0.,
+        }
+    }
+}
+
+// Chance of being struck on the next bolt, 0 to 1
+#[derive(Resource, Default)]
+pub struct StrikeRisk(pub f32);
+
+#[derive(Component)]
+pub struct LightningFlashOverlay;
+
+pub fn run_if_thunderstorm(weather_state: Res<WeatherState>, current_region: Res<State<Region>>) -> bool {
+    let current_weather = weather_state.weather_by_region.get(current_region.get()).unwrap_or(&Weather::Sunny);
+    return *current_weather == Weather::Thunderstorm;
+}
+
+pub fn lightning_strikes(
+    time: Res<GameDayTimer>,
+    mut lightning: ResMut<LightningState>,
+    mut strike_events: EventWriter<LightningStrikeEvent>,
+) {
+    if !lightning.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let mut rng = rand::thread_rng();
+    let next_strike = rng.gen_range(STRIKE_INTERVAL.0..STRIKE_INTERVAL.1);
+    lightning.timer = Timer::from_seconds(next_strike, TimerMode::Once);
+    lightning.flash = 1.;
+
+    strike_events.send(LightningStrikeEvent);
+}
+
+pub fn lightning_flash(
+    time: Res<Time>,
+    mut lightning: ResMut<LightningState>,
+    current_interface: Res<State<CurrentInterface>>,
+    mut overlay: Query<&mut Sprite, With<LightningFlashOverlay>>,
+) {
+    lightning.flash = (lightning.flash - FLASH_DECAY * time.delta_seconds()).max(0.);
+
+    if let Ok(mut sprite) = overlay.get_single_mut() {
+        let alpha = if current_interface.eq(&CurrentInterface::Shop) {
+            0.
+        } else {
+            lightning.flash * FLASH_MAX_ALPHA
+        };
+
+        sprite.color = Color::srgba(1., 1., 0.95, alpha);
+    }
+}
+
+pub fn update_strike_risk(
+    time: Res<GameDayTimer>,
+    weather_state: Res<WeatherState>,
+    current_region: Res<State<Region>>,
+    current_interface: Res<State<CurrentInterface>>,
+    fishing_state: Res<State<FishingState>>,
+    mut risk: ResMut<StrikeRisk>,
+) {
+    let current_weather = weather_state.weather_by_region.get(current_region.get()).unwrap_or(&Weather::Sunny);
+    let exposed = *current_weather == Weather::Thunderstorm
+        && current_interface.eq(&CurrentInterface::Fishing)
+        && !fishing_state.eq(&FishingState::Idle);
+
+    let dt = time.delta().as_secs_f32();
+
+    risk.0 = if exposed {
+        (risk.0 + RISK_GROWTH * dt).min(1.)
+    } else {
+        (risk.0 - RISK_DECAY * dt).max(0.)
+    };
+}
+
+pub fn lightning_hazard(
+    mut commands: Commands,
+    mut strike_events: EventReader<LightningStrikeEvent>,
+    mut risk: ResMut<StrikeRisk>,
+    current_interface: Res<State<CurrentInterface>>,
+    mut next_interface: ResMut<NextState<CurrentInterface>>,
+    mut next_fishing_state: ResMut<NextState<FishingState>>,
+    mut start_fishing_animation: ResMut<StartFishingAnimation>,
+    mut hooked_object: Query<(Entity, &mut PhysicsObject), With<Hooked>>,
+    mut fishing_button: Query<&mut Visibility, With<FishingButton>>,
+) {
+    for _ in strike_events.read() {
+        if !current_interface.eq(&CurrentInterface::Fishing) {
+            continue;
+        }
+
+        let roll: f32 = rand::thread_rng().gen();
+
+        if roll >= risk.0 {
+            continue;
+        }
+
+        // Struck, drop whatever is on the line and run for cover
+        println!("Lightning struck the rod! Retreating to shore");
+
+        for (entity_id, mut physics_object) in hooked_object.iter_mut() {
+            commands.entity(entity_id).remove::<Hooked>();
+            physics_object.forces.player = Vec3::ZERO;
+        }
+
+        risk.0 = 0.;
+        next_fishing_state.set(FishingState::Idle);
+        if let Ok(mut visibility) = fishing_button.get_single_mut() {
+            exit_fishing(&mut start_fishing_animation, &mut visibility, &mut next_interface);
+        }
+    }
+}
+
+pub fn spawn_lightning_flash_overlay(mut commands: Commands){
+    commands.spawn((SpriteBundle {
+        sprite: Sprite {
+            color: Color::srgba(1., 1., 0.95, 0.0),
+            custom_size: Some(Vec2::new(20000.,20000.)),
+            ..default()
+        },
+        transform: Transform::from_xyz(0.0, 0.0, 999.1),
+       ..default()
+    },
+    LightningFlashOverlay
+    ));
+}
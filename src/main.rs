@@ -13,6 +13,13 @@ use fishing_game::resources::*;
 use fishing_game::button::*;
 use fishing_game::gameday::*;
 use fishing_game::weather::*;
+use fishing_game::lightning::*;
+use fishing_game::tide::*;
+use fishing_game::bottom::*;
+use fishing_game::junk::*;
+use fishing_game::depth_inset::*;
+use fishing_game::water_clarity::*;
+use fishing_game::water_temperature::*;
 use fishing_game::fishing_view::*;
 use fishing_game::fishing_zone::*;
 use fishing_game::shop::*;
@@ -48,11 +55,20 @@ fn main() {
         .init_state::<FishingLocal>()
         .init_state::<MidnightState>()
         .init_resource::<WeatherState>()
-        .add_systems(Startup, (setup, spawn_weather_tint_overlay, spawn_day_tint_overlay))
+        .init_resource::<WindState>()
+        .init_resource::<PressureState>()
+        .init_resource::<LightningState>()
+        .init_resource::<StrikeRisk>()
+        .init_resource::<Tide>()
+        .init_resource::<WaterTemperature>()
+        .init_resource::<WaterClarity>()
+        .init_resource::<Bottom>()
+        .init_resource::<JunkTable>()
+        .add_systems(Startup, (setup, spawn_weather_tint_overlay, spawn_day_tint_overlay, spawn_lightning_flash_overlay, spawn_tide_shoreline, spawn_depth_inset))
 
     
         //Run the game timer
-        .add_systems(Update, run_game_timer)
+        .add_systems(Update, (time_controls, run_game_timer).chain())
         .add_systems(Update, day_tint.after(run_game_timer))
 
         // Run the button system in both FishingMode and Overworld
@@ -61,6 +77,12 @@ fn main() {
         .add_systems(Update, update_money_display)
         .add_systems(Update, update_clock_display)
         .add_systems(Update, update_weather_display)
+        .add_systems(Update, update_strike_risk_display)
+        .add_systems(Update, update_wind_display)
+        .add_systems(Update, update_moon_display)
+        .add_systems(Update, update_water_temp_display)
+        .add_systems(Update, update_barometer_display)
+        .add_systems(Update, update_depth_inset)
 
         .add_systems(Update, handle_inventory.run_if(in_state(CurrentInterface::Overworld)))
 
@@ -78,11 +100,27 @@ fn main() {
 
         // Weather updates
         .add_event::<RegionChangedEvent>()
-        .add_systems(Update, ( handle_region_change, update_weather, update_player_region))
+        .add_systems(Update, ( handle_region_change, update_weather.after(run_game_timer), update_player_region))
         .add_systems(Update, update_weather_tint.after(update_weather))
+        .add_systems(Update, update_wind.after(update_weather))
+        .add_systems(Update, update_pressure.after(update_weather))
         .add_systems(Update, rain_particle_system.run_if(run_if_raining))
         .add_systems(OnEnter(Weather::Sunny), despawn_rain_particles)
         .add_systems(OnEnter(Weather::Cloudy), despawn_rain_particles)
+        .add_systems(OnEnter(Weather::Fog), despawn_rain_particles)
+        .add_systems(OnEnter(Weather::Snow), despawn_rain_particles)
+
+        // Lightning storms
+        .add_event::<LightningStrikeEvent>()
+        .add_systems(Update, lightning_strikes.after(run_game_timer).run_if(run_if_thunderstorm))
+        .add_systems(Update, (lightning_flash, lightning_hazard).after(lightning_strikes))
+        .add_systems(Update, update_strike_risk.after(run_game_timer))
+
+        // Ocean tides
+        .add_systems(Update, (update_tide, update_tide_shoreline).chain().after(run_game_timer))
+
+        // Water temperature and clarity
+        .add_systems(Update, (update_water_temperature, update_water_clarity).after(update_weather))
         
         // Check if we've hooked any fish
         //.add_systems(Update, hook_fish)     
@@ -144,5 +182,10 @@ fn setup(
     spawn_money_display(&mut commands, &asset_server);
     spawn_clock_display(&mut commands, &asset_server);
     spawn_weather_display(&mut commands, &asset_server);
+    spawn_wind_display(&mut commands, &asset_server);
+    spawn_strike_risk_display(&mut commands, &asset_server);
+    spawn_moon_display(&mut commands, &asset_server);
+    spawn_water_temp_display(&mut commands, &asset_server);
+    spawn_barometer_display(&mut commands, &asset_server);
     spawn_hint(&mut commands, &asset_server);
 }
\ No newline at end of file
@@ -192,9 +192,7 @@ fn setup(
     // MAP
     let mut map: Map = Map {
         areas: vec![vec![Area {
-            zone: FishingZone {
-                current: Vec3::new(-10.0, 0., 0.)
-            },
+            zone: FishingZone::DEFAULT,
             layout: [[&Tile::EMPTY; GRID_ROWS]; GRID_COLUMNS],
             objects: Vec::new()
         }; MAP_HEIGHT]; MAP_WIDTH],
@@ -202,6 +200,10 @@ fn setup(
         height: MAP_HEIGHT
     };
 
+    // Areas without their own currents file share the default one
+    let default_zone = FishingZone::load("assets/map/default.currents")
+        .unwrap_or(FishingZone::uniform(Vec3::new(-10.0, 0., 0.)));
+
     for area_x in 0..map.width {
         let start_x = area_x as f32 * WIN_W + TILE_SIZE / 2. - WIN_W / 2.;
         let center_x = area_x as f32 * WIN_W;
@@ -212,6 +214,10 @@ fn setup(
             // Load area data
             let filename = "assets/map/".to_owned() + &area_x.to_string() + "_" + &area_y.to_string();
 
+            // Load water currents
+            area.zone = FishingZone::load(&(filename.clone() + ".currents"))
+                .unwrap_or(default_zone.clone());
+
             if let Ok(mut lines) = read_lines(&filename) {
                 // Load tiles
                 for x in 0..GRID_COLUMNS {
@@ -4,6 +4,11 @@ use crate::species::*;
 use crate::fishing_view::*;
 use crate::player::*;
 use crate::map::*;
+use crate::weather::*;
+use crate::tide::*;
+use crate::bottom::*;
+use crate::snag::*;
+use crate::jump::*;
 use std::f32;
 use f32::consts::PI;
 use std::collections::HashSet;
@@ -12,22 +17,71 @@ use rand::Rng;
 use crate::species::Behavior;
 
 const REEL: KeyCode = KeyCode::KeyO;
+const TWITCH: KeyCode = KeyCode::KeyP;
+const JIG: KeyCode = KeyCode::KeyJ;
 
 pub const ROD_RADIUS_PIXELS_PER_METER: f32 = 750.;
 pub const PIXELS_PER_METER: f32 = 300.;
 pub const BENDING_RESOLUTION: f32 = 1. / PIXELS_PER_METER;
+// The water column isn't drawn to scale, one unit of z below the surface is a
+// metre of depth, the same units as Lure::depth and Species::depth
+pub const DEPTH_UNITS_PER_METER: f32 = 1.;
 
 pub const GRAVITY: f32 = 40.;
 
+// Longest step the rod's spring is moved on by at once, so a slow frame can't blow it up
+const ROD_MAX_STEP: f32 = 1. / 120.;
+
+// Line tension below this counts as slack
+pub const SLACK_TENSION: f32 = 60.;
+
 const MAX_PLAYER_FORCE: f32 = 600.;
 const MAX_PLAYER_POWER: f32 = MAX_PLAYER_FORCE * 60.;
 const P: f32 = 1. / 250.;
 
+// How hard a rod swung off to the side steers the fish's head round
+const SIDE_PRESSURE: f32 = 0.8;
+// Lifting power lost with the rod at right angles to the line
+const LEVER_LOSS: f32 = 0.3;
+// Extra strain on the line with the rod pointed straight at the fish and
+// nothing to soak up the lunges
+const POINTED_STRAIN: f32 = 0.5;
+
+// Lures shallower than this catch the wind
+const SURFACE_DEPTH: f32 = 2.;
+const WIND_DRAG: f32 = 0.02;
+const WIND_SURFACE_SPEED: f32 = 2.;
+
+// Sharp pull towards the rod from a twitch, pixels per second
+const TWITCH_SPEED: f32 = 150.;
+// Lift from raising the rod tip on a jig, metres per second
+const JIG_LIFT_SPEED: f32 = 3.;
+
+// How quickly the lure motion signal forgets old movement, per second
+const MOTION_DECAY: f32 = 1.5;
+// Signal levels that count as each retrieve
+const JIG_SIGNAL: f32 = 1.5;
+const TWITCH_SIGNAL: f32 = 100.;
+const STEADY_SPEED: f32 = 20.;
+
+// Turning, x and y are in pixels so torques are too. The moment of inertia is
+// as if all the mass sat this far from the middle of the body.
+const BODY_RADIUS: f32 = 40.;
+// How far behind the middle the water pushes, so drag swings the head into the flow
+const WEATHERVANE_ARM: f32 = 10.;
+// How hard a hooked fish turns itself, radians per second squared when side on to where it wants to go
+const FISH_STEER: f32 = 8.;
+// How quickly the water slows a spin, per second
+const ANGULAR_DRAG: f32 = 3.;
+
 #[derive(Component)]
 pub struct PhysicsObject {
     pub mass: f32,
     pub position: Vec3,
-    pub rotation: Vec3,
+    pub rotation: Quat,
+    pub angular_velocity: Vec3,
+    // Added up by the force systems each frame and used up when simulating
+    pub torque: Vec3,
     pub velocity: Vec3,
     pub forces: Forces,
     pub cd: (f32, f32),
@@ -36,8 +90,28 @@ pub struct PhysicsObject {
 }
 
 impl PhysicsObject {
-    pub fn new(mass: f32, position: Vec3, rotation: Vec3, velocity: Vec3, forces: Forces, cd: (f32, f32), sa: (f32, f32), waves: Entity) -> Self {
-        Self { mass, position, rotation, velocity, forces, cd, sa, waves }
+    pub fn new(mass: f32, position: Vec3, rotation: Quat, velocity: Vec3, forces: Forces, cd: (f32, f32), sa: (f32, f32), waves: Entity) -> Self {
+        Self { mass, position, rotation, angular_velocity: Vec3::ZERO, torque: Vec3::ZERO, velocity, forces, cd, sa, waves }
+    }
+
+    // Sprites face left, so the head is along -x before any rotation
+    pub fn forward(&self) -> Vec3 {
+        self.rotation * Vec3::NEG_X
+    }
+
+    // Which way the sprite is turned in the water's plane, sprites are flat so that's all that gets drawn
+    pub fn heading(&self) -> f32 {
+        let forward = self.forward();
+        f32::atan2(forward.y, forward.x) + PI
+    }
+
+    pub fn inertia(&self) -> f32 {
+        self.mass * BODY_RADIUS * BODY_RADIUS
+    }
+
+    // Metres below the surface, 0 at or above it
+    pub fn depth_meters(&self) -> f32 {
+        (-self.position.z / DEPTH_UNITS_PER_METER).max(0.)
     }
 }
 
@@ -47,28 +121,55 @@ pub struct Forces {
     pub player: Vec3,
     pub water: Vec3,
     pub gravity: Vec3,
-    pub buoyancy: Vec3
+    pub buoyancy: Vec3,
+    pub wind: Vec3
 }
 
 impl Forces {
     pub fn net_force(&self) -> Vec3 {
-        return self.own + self.player + self.water + self.gravity + self.buoyancy;
+        return self.own + self.player + self.water + self.gravity + self.buoyancy + self.wind;
     }
 }
 
 #[derive(Component)]
 pub struct Hooked;
 
+// What a fish sees of the way the lure is moving. Sudden changes of speed
+// and upward lifts build up and fade away, the speed is smoothed.
+#[derive(Component, Default)]
+pub struct LureMotion {
+    pub speed: f32,
+    pub twitch: f32,
+    pub lift: f32,
+    last_velocity: Vec3,
+}
+
+impl LureMotion {
+    pub fn style(&self) -> RetrieveStyle {
+        if self.lift > JIG_SIGNAL {
+            RetrieveStyle::Jig
+        } else if self.twitch > TWITCH_SIGNAL {
+            RetrieveStyle::Twitch
+        } else if self.speed > STEADY_SPEED {
+            RetrieveStyle::Steady
+        } else {
+            RetrieveStyle::Still
+        }
+    }
+}
+
 pub fn bend_fishing_rod (
     mut commands: Commands,
     mut fishing_rod: Query<(&mut FishingRod, &Transform), With<FishingRod>>,
     mut line: Query<&mut FishingLine, With<FishingLine>>,
-    hooked_object: Query<&PhysicsObject, With<Hooked>>
+    hooked_object: Query<&PhysicsObject, With<Hooked>>,
+    time: Res<Time>,
 ) {
     let (mut rod_info, rod_transform) = fishing_rod.single_mut();
     let mut line_info = line.single_mut();
     
     let traverse_force: f32;
+    let mut tension = 0.;
     let mut rotation: Quat = Quat::from_rotation_z(rod_info.rotation);
     let line_rotation: Quat = Quat::from_rotation_z(rod_info.rotation).mul_quat(Quat::from_rotation_x(PI / 2.));
 
@@ -85,12 +186,31 @@ pub fn bend_fishing_rod (
         let end1_force = physics_object.forces.player.length();
         let end2_force = (physics_object.forces.water + physics_object.forces.own).dot(line_dir);
         traverse_force = end1_force + end2_force;
+        tension = line_tension(physics_object, &line_info);
 
         let angle = if line_dir == Vec3::ZERO { 0. } else { (rod_info.rotation - f32::atan2(line_dir.y, line_dir.x )).signum() * line_dir.with_z(0.).angle_between(rod_dir) };
         rotation = rotation.mul_quat(Quat::from_rotation_x(angle + PI / 2.));
     };
 
     let rod_type = rod_info.rod_type;
+
+    // The blank is a damped spring, the load on it lags behind a sudden pull,
+    // overshoots and rings on for a bit after. That's only the drawn bend, the
+    // line gets the same lag critically damped so the rod never adds to a peak.
+    let omega = 2. * PI * rod_type.action;
+    let steps = (time.delta_seconds() / ROD_MAX_STEP).ceil().max(1.);
+    let step = time.delta_seconds() / steps;
+    for _ in 0..steps as usize {
+        let acceleration = omega * omega * (traverse_force - rod_info.load) - 2. * rod_type.damping * omega * rod_info.load_speed;
+        rod_info.load_speed += acceleration * step;
+        rod_info.load += rod_info.load_speed * step;
+
+        let acceleration = omega * omega * (tension - rod_info.cushioned) - 2. * omega * rod_info.cushioned_speed;
+        rod_info.cushioned_speed += acceleration * step;
+        rod_info.cushioned += rod_info.cushioned_speed * step;
+    }
+    let load = rod_info.load;
+
     let thickness_ratio = rod_type.thickness / rod_type.radius;
     let thickness_ratio_inverse = 1. - thickness_ratio;
 
@@ -102,7 +222,7 @@ pub fn bend_fishing_rod (
     for i in 0..rod_info.segments.len() {
         // Calculate position of each segment
         let l = i as f32 * BENDING_RESOLUTION;
-        let bending_moment_area = 0.5 * (l + l + BENDING_RESOLUTION) * traverse_force * BENDING_RESOLUTION;
+        let bending_moment_area = 0.5 * (l + l + BENDING_RESOLUTION) * load * BENDING_RESOLUTION;
         let r2 = rod_type.radius * (thickness_ratio + l / rod_type.length * thickness_ratio_inverse);
         let r1 = r2 - rod_type.thickness;
         let second_moment_area = PI / 4. * (r2 * r2 * r2 * r2 - r1 * r1 * r1 * r1);
@@ -113,7 +233,7 @@ pub fn bend_fishing_rod (
 
         // Check if fishing rod will break
         let area = PI * (r2 * r2 - r1 * r1);
-        let stress = traverse_force * l / area;
+        let stress = load * l / area;
 
         if stress > rod_info.rod_type.flexural_strength {
             // BREAK
@@ -159,11 +279,25 @@ pub fn get_particle_positions(
 
 }
 
+// Pull along the line between the rod and whatever's on the end of it
+pub fn line_tension(physics_object: &PhysicsObject, line_info: &FishingLine) -> f32 {
+    let line_dir = (line_info.end - line_info.start).normalize_or_zero();
+    -physics_object.forces.player.dot(line_dir) + physics_object.forces.water.dot(line_dir) + physics_object.forces.own.dot(line_dir)
+}
+
+// How far the rod is swung off the line, 0 pointed straight down it at the
+// fish and 1 at right angles where the blank bends and cushions the most
+pub fn rod_cushion(rod_info: &FishingRod, line_info: &FishingLine) -> f32 {
+    let line_dir = (line_info.end - line_info.start).truncate().normalize_or_zero();
+    Vec2::from_angle(rod_info.rotation).perp_dot(line_dir).abs()
+}
+
 pub fn is_line_broken (
     mut commands: Commands,
     mut next_state: ResMut<NextState<FishingState>>,
     mut hooked_object: Query<(Entity, &mut PhysicsObject), With<Hooked>>,
-    line: Query<&FishingLine, With<FishingLine>>
+    line: Query<&FishingLine, With<FishingLine>>,
+    rod: Query<&FishingRod>,
 ){
     if hooked_object.is_empty() {
         return;
@@ -171,11 +305,14 @@ pub fn is_line_broken (
 
     let (entity_id, mut physics_object) = hooked_object.single_mut();
     let line_info = line.single();
-    
-    let line_dir = (line_info.end - line_info.start).normalize();
-    let tension = -physics_object.forces.player.dot(line_dir) + physics_object.forces.water.dot(line_dir) + physics_object.forces.own.dot(line_dir);
 
-    if tension > line_info.line_type.ultimate_tensile_strength {
+    // The bending rod takes the edge off a sudden lunge, unless it's pointed
+    // straight at the fish when every lunge goes onto the line
+    let rod_info = rod.single();
+    let cushion = rod_cushion(rod_info, line_info);
+    let tension = line_tension(&physics_object, line_info);
+    let strain = 1. + POINTED_STRAIN * (1. - cushion);
+    if (tension + (rod_info.cushioned - tension) * cushion) * strain > line_info.line_type.ultimate_tensile_strength {
         commands.entity(entity_id).remove::<Hooked>();
         physics_object.forces.player = Vec3::ZERO;
         next_state.set(FishingState::Idle);
@@ -196,15 +333,37 @@ pub fn calculate_buoyancy_force (
     lure_physics.forces.buoyancy = Vec3::new(0., 0., buoyancy);
 }
 
+pub fn calculate_wind_force (
+    wind: Res<WindState>,
+    region: Res<State<Region>>,
+    mut lure: Query<(&Lure, &mut PhysicsObject, Has<Hooked>), With<Lure>>
+) {
+    let (lure_info, mut lure_physics, on_line) = lure.single_mut();
+
+    if !on_line || lure_physics.position.z < -SURFACE_DEPTH {
+        lure_physics.forces.wind = Vec3::ZERO;
+        return;
+    }
+
+    // Drag from the air moving past the part of the lure above the water
+    let air_velocity = (wind.get(region.get()) * WIND_SURFACE_SPEED).extend(0.);
+    let relative_velocity = air_velocity - lure_physics.velocity.with_z(0.);
+    lure_physics.forces.wind = WIND_DRAG * lure_info.sa.1 * relative_velocity;
+}
+
 pub fn calculate_water_force (
     map: Res<Map>,
     mut physics_objects: Query<&mut PhysicsObject>,
-    player: Query<&Location, With<Player>>
+    player: Query<&Location, With<Player>>,
+    fishing_location: Res<State<FishingLocal>>,
+    tide: Res<Tide>,
 ) {
     let player_location = player.single();
-    let water_current = map.areas[player_location.x][player_location.y].zone.current;
+    let zone = &map.areas[player_location.x][player_location.y].zone;
 
     for mut physics_object in physics_objects.iter_mut() {
+        // Sample the current where the object actually is
+        let water_current = zone.current_at(fishing_location.get(), physics_object.position, &tide);
         let relative_velocity = physics_object.velocity - water_current;
 
         if physics_object.position.z > 0. || relative_velocity == Vec3::ZERO {
@@ -212,34 +371,67 @@ pub fn calculate_water_force (
             continue;
         }
 
-        let angle = Vec2::from_angle(physics_object.rotation.z).extend(0.).angle_between(water_current);
-        let proportion = (PI / 2. - f32::abs(angle - PI / 2.)) / (PI / 2.);
+        // Angle of attack, 0 moving head or tail first through the water and 1 broadside
+        let flow = relative_velocity.normalize();
+        let side_on = physics_object.forward().cross(flow);
+        let proportion = side_on.length();
         let sa = physics_object.sa.0 + (physics_object.sa.1 - physics_object.sa.0) * proportion;
         let cd = physics_object.cd.0 + (physics_object.cd.1 - physics_object.cd.0) * proportion;
-    
-        physics_object.forces.water = P * cd * sa * relative_velocity.length() * relative_velocity.length() * -relative_velocity.normalize();
+
+        physics_object.forces.water = P * cd * sa * relative_velocity.length() * relative_velocity.length() * -flow;
+
+        // Drag pushes behind the middle and swings the head round the way it's moving
+        let drag = physics_object.forces.water.length();
+        physics_object.torque += WEATHERVANE_ARM * drag * side_on;
     }
 }
 
 pub fn calculate_player_force (
     input: Res<ButtonInput<KeyCode>>,
     fishing_rod: Query<&FishingRod, With<FishingRod>>,
-    mut hooked_object: Query<&mut PhysicsObject, With<Hooked>>,
+    mut hooked_object: Query<(&mut PhysicsObject, Has<Lure>, Option<&Wrapped>), With<Hooked>>,
 ) {
     if hooked_object.is_empty() {
         return;
     }
 
     let rod_info = fishing_rod.single();
-    let mut object_physics = hooked_object.single_mut();
+    let (mut object_physics, is_lure, wrapped) = hooked_object.single_mut();
+    // A line wrapped around structure pulls from there instead of the rod
+    let pull_from = wrapped.map_or(rod_info.tip_pos, |wrap| wrap.around);
 
     let reeling = input.pressed(REEL);
 
+    // Working the lure, a twitch snaps it towards the rod and a jig lifts it
+    if is_lure {
+        if input.just_pressed(TWITCH) {
+            let delta = rod_info.tip_pos - object_physics.position;
+            object_physics.velocity += TWITCH_SPEED * delta.with_z(0.).normalize_or_zero();
+        }
+
+        if input.just_pressed(JIG) && object_physics.position.z < 0. {
+            object_physics.velocity.z += JIG_LIFT_SPEED;
+        }
+    }
+
     object_physics.forces.player = if reeling {
-        let delta = rod_info.tip_pos - object_physics.position;
+        let delta = pull_from - object_physics.position;
         let force = (MAX_PLAYER_POWER / object_physics.velocity.length()).min(MAX_PLAYER_FORCE);
+        let mut pull = delta.normalize_or_zero();
+        let mut leverage = 1.;
+
+        // Side pressure, the line pulls the fish round towards wherever the
+        // rod is swung, but with less of the rod's lifting power behind it
+        if !is_lure && wrapped.is_none() {
+            let to_tip = delta.truncate().normalize_or_zero();
+            let rod_dir = Vec2::from_angle(rod_info.rotation);
+            let side = rod_dir - to_tip * rod_dir.dot(to_tip);
+
+            pull = (pull + SIDE_PRESSURE * side.extend(0.)).normalize_or_zero();
+            leverage -= LEVER_LOSS * side.length();
+        }
 
-        force * delta.normalize_or_zero()
+        force * leverage * pull
     } else {
         Vec3::ZERO
     };
@@ -276,45 +468,44 @@ pub fn calculate_fish_force(
         println!("Behavior multiplier: {}", behavior_multiplier);
 
         let fish_position = fish_physics.position;
+        let direction_away_from_rod = (fish_position - rod_info.tip_pos).normalize_or_zero();
 
-        let direction = match species.behavior {
+        let (target_direction, speed) = match species.behavior {
             Behavior::Aggressive => {
-                let pause = 0.05; // how quickly the direction changes (lower = slower)
-                let mut rng = rand::thread_rng();
-                let direction_away_from_rod = (fish_position - rod_info.tip_pos).normalize_or_zero();
-                let rand_speed = rng.gen_range(0.5..1.2); 
-                let target_direction = direction_away_from_rod * rand_speed;
-                let direction = fish_physics.rotation.lerp(target_direction, pause);
-                fish_physics.rotation = direction;
-                println!("AGGRESSIVE fish is moving unpredictably! direction: {:?}, Speed: {}", direction, rand_speed);
-                direction
+                let rand_speed = rand::thread_rng().gen_range(0.5..1.2);
+                println!("AGGRESSIVE fish is moving unpredictably! direction: {:?}, Speed: {}", direction_away_from_rod, rand_speed);
+                (direction_away_from_rod, rand_speed)
             }
             Behavior::Evasive => {
                 // evasive fish flee from the rod
-                let direction = (fish_position - rod_info.tip_pos).normalize_or_zero();
-                println!("EVASIVE fish is fleeing from the rod. direction: {:?}", direction);
-                direction
+                println!("EVASIVE fish is fleeing from the rod. direction: {:?}", direction_away_from_rod);
+                (direction_away_from_rod, 1.)
             }
             Behavior::Passive => {
                 // passive fish do nothing
                 println!("PASSIVE fish is letting you reel him in for dinner");
-                Vec3::ZERO
+                (Vec3::ZERO, 0.)
             }
             Behavior::Elusive => {
                 // elusive fish get wild
-                let pause = 0.05; // how quickly the direction changes (lower = slower)
-                let mut rng = rand::thread_rng();
-                let direction_away_from_rod = (fish_position - rod_info.tip_pos).normalize_or_zero();
-                let rand_speed = rng.gen_range(0.5..1.2); 
-                let target_direction = direction_away_from_rod * rand_speed;
-                let direction = fish_physics.rotation.lerp(target_direction, pause);
-                fish_physics.rotation = direction;
-                println!("ELUSIVE fish is moving away from the rod. direction: {:?}, Speed: {}", direction, rand_speed);
-                direction
+                let rand_speed = rand::thread_rng().gen_range(0.5..1.2);
+                println!("ELUSIVE fish is moving away from the rod. direction: {:?}, Speed: {}", direction_away_from_rod, rand_speed);
+                (direction_away_from_rod, rand_speed)
                 //theyre basically just aggro behavior rn but much more x
             }
         };
 
+        // the fish turns itself towards where it wants to go and swims the way it's facing
+        let forward = fish_physics.forward();
+        let inertia = fish_physics.inertia();
+        fish_physics.torque += FISH_STEER * inertia * forward.cross(target_direction);
+        let direction = forward * speed;
+
+        // the line pulls on the mouth, not the middle, so it turns the fish too
+        let mouth = fish_physics.rotation * species.hook_pos.extend(0.);
+        let player_force = fish_physics.forces.player;
+        fish_physics.torque += mouth.cross(player_force);
+
         // scale direction by anger level and behavior multiplier 
         let calculated_force = direction * anger * behavior_multiplier;
 
@@ -328,29 +519,61 @@ pub fn calculate_fish_force(
 
 
 
+pub fn track_lure_motion(
+    time: Res<Time>,
+    mut lure: Query<(&PhysicsObject, &mut LureMotion), With<Lure>>,
+) {
+    let (lure_physics, mut motion) = lure.single_mut();
+    let decay = f32::exp(-MOTION_DECAY * time.delta_seconds());
+    let change = lure_physics.velocity - motion.last_velocity;
+
+    motion.twitch = motion.twitch * decay + change.truncate().length();
+    motion.lift = motion.lift * decay + change.z.max(0.);
+    motion.speed = motion.speed * decay + lure_physics.velocity.truncate().length() * (1. - decay);
+    motion.last_velocity = lure_physics.velocity;
+}
+
 pub fn simulate_physics (
     time: Res<Time>,
-    mut objects: Query<&mut PhysicsObject, With<PhysicsObject>>
+    bottom: Res<Bottom>,
+    fishing_location: Res<State<FishingLocal>>,
+    tide: Res<Tide>,
+    mut objects: Query<(&mut PhysicsObject, Has<Airborne>), With<PhysicsObject>>
 ) {
-    for mut object in objects.iter_mut() {
+    for (mut object, airborne) in objects.iter_mut() {
         // Calculate net force and acceleration
-        let acceleration = object.forces.net_force() / object.mass;
+        let mut acceleration = object.forces.net_force() / object.mass;
+
+        // Nothing holds a jumping fish up once it's out of the water
+        if airborne && object.position.z > 0. {
+            acceleration.z -= AIR_GRAVITY * DEPTH_UNITS_PER_METER;
+        }
         object.velocity = object.velocity + acceleration * time.delta_seconds();
 
         // Bounds check
         let mut new_pos = object.position + object.velocity * time.delta_seconds();
         
-        // Surface collision
-        if new_pos.z > 0. {
+        // Surface collision, jumping fish go over it
+        if new_pos.z > 0. && !airborne {
             new_pos.z = 0.;
             object.velocity.z = 0.;
         }
 
+        // Bottom collision
+        let floor = bottom.floor_z(fishing_location.get(), new_pos, &tide);
+        if new_pos.z < floor {
+            new_pos.z = floor;
+            object.velocity.z = object.velocity.z.max(0.);
+        }
+
         object.position = new_pos;
 
-        // Calculate rotation
-        if object.velocity.x != 0. || object.velocity.y != 0. {
-            object.rotation.z = f32::atan2(object.velocity.y, object.velocity.x) + PI;
-        }
+        // Turn under this frame's torque, the water slows any spin
+        let angular_acceleration = object.torque / object.inertia();
+        object.angular_velocity = (object.angular_velocity + angular_acceleration * time.delta_seconds())
+            * f32::exp(-ANGULAR_DRAG * time.delta_seconds());
+        let spin = Quat::from_scaled_axis(object.angular_velocity * time.delta_seconds());
+        object.rotation = (spin * object.rotation).normalize();
+        object.torque = Vec3::ZERO;
     }
 }
\ No newline at end of file
@@ -8,13 +8,110 @@ use crate::fishing_view::*;
 use crate::inventory::*;
 
 const BASE_HUNGER_PROB: f32 = 0.7;
-const INC_IND: usize = 32;
-const AGE_OLD: usize = 1;
-const W_NOT_PREF: usize = 2;
-const T_NOT_PREF: usize = 4;
-const LOW_HOOK: usize = 8;
-const MED_HOOK: usize = 16;
-const HIGH_HOOK: usize  = 24;
+const INC_IND: usize = 64;
+const MOON_NOT_BRIGHT: usize = 1;
+const AGE_OLD: usize = 2;
+const W_NOT_PREF: usize = 4;
+const T_NOT_PREF: usize = 8;
+const LOW_HOOK: usize = 16;
+const MED_HOOK: usize = 32;
+const HIGH_HOOK: usize  = 48;
+
+// Extra bite chance for storm-loving species while a storm is overhead
+const STORM_BITE_BOOST: f32 = 1.5;
+
+// Fish are sluggish in the cold and get hungry more slowly while it snows
+const SNOW_ACTIVITY: f32 = 0.5;
+
+// Night feeders hunt harder under a bright moon, day feeders have already eaten.
+// How much the moon counts for each, see h_e.
+const MOON_NOCTURNAL_STRENGTH: f32 = 1.;
+const MOON_DAYTIME_STRENGTH: f32 = 0.25;
+// Share of the time a bright moon is up, 3 of the 8 phases and only at night
+const BRIGHT_MOON_SHARE: f32 = 3. / 8. * 10. / 24.;
+
+// Range of water temperatures a fish could meet, used as the prior for
+// temperature preference
+const TEMP_SPAN: f32 = 30.;
+
+// Bite chance lost to a fully visible line in perfectly clear water
+const LINE_SPOOK: f32 = 0.6;
+
+// Fish feed hard on a falling barometer ahead of a front, about a third of the time
+const FALLING_PRESSURE_SHARE: f32 = 1. / 3.;
+const FALLING_PRESSURE_STRENGTH: f32 = 1.;
+
+// Lure moving the way the species likes to chase, one of the four retrieves
+const RETRIEVE_SHARE: f32 = 1. / 4.;
+const RETRIEVE_STRENGTH: f32 = 1.;
+// A fish that just got off the hook wants nothing to do with another lure
+const HOOK_SHY_PENALTY: f32 = 0.9;
+
+// Chance once one more piece of evidence is in, from the chance before it b
+// and the share of the time the evidence turns up e. Takes
+// P(E|H) = e + (1 - e) * e * (1 - b) * strength through Bayes, so with a
+// strength up to 1 both sides stay between 0 and 1 and average back out to b.
+fn h_e(e: f32, b: f32, strength: f32) -> f32 {
+    b * (1. + (1. - e) * (1. - b) * strength)
+}
+
+fn h_not_e(e: f32, b: f32, strength: f32) -> f32 {
+    b * (1. - e * (1. - b) * strength)
+}
+
+// A bright moon helps night feeders, a dark night helps day feeders
+fn given_moon(nocturnal: bool, bright_moon: bool, b: f32) -> f32 {
+    if nocturnal {
+        if bright_moon {
+            h_e(BRIGHT_MOON_SHARE, b, MOON_NOCTURNAL_STRENGTH)
+        } else {
+            h_not_e(BRIGHT_MOON_SHARE, b, MOON_NOCTURNAL_STRENGTH)
+        }
+    } else if bright_moon {
+        h_not_e(1. - BRIGHT_MOON_SHARE, b, MOON_DAYTIME_STRENGTH)
+    } else {
+        h_e(1. - BRIGHT_MOON_SHARE, b, MOON_DAYTIME_STRENGTH)
+    }
+}
+
+// The CPT covers what a fish is in the mood for. These go on top of it
+// because they aren't yes/no evidence about the fish:
+//  - storm-loving species feed in a frenzy with a storm overhead
+//  - a line the fish can see puts it off, line_seen is 0 to 1
+//  - a fish that just got off the hook is wary, hook_shy is 0 to 1
+fn after_cpt(storm_frenzy: bool, line_seen: f32, hook_shy: f32) -> f32 {
+    let storm = if storm_frenzy { STORM_BITE_BOOST } else { 1. };
+    storm * (1. - LINE_SPOOK * line_seen) * (1. - HOOK_SHY_PENALTY * hook_shy)
+}
+
+// Weather, pressure and time where the player is fishing
+pub struct BiteConditions<'a> {
+    pub weather: &'a WeatherState,
+    pub pressure: &'a PressureState,
+    pub region: &'a Region,
+    pub timer: &'a GameDayTimer,
+}
+
+// The lure as a fish near it sees it
+pub struct LurePresentation<'a> {
+    pub lure: &'a Lure,
+    pub depth: f32,
+    pub temp: f32,
+    pub retrieve: RetrieveStyle,
+    pub line_seen: f32,
+}
+
+// Evidence the hook CPT is indexed by
+pub struct BiteEvidence {
+    pub time_pref: bool,
+    pub weather_pref: bool,
+    pub depth_pref: bool,
+    pub lure_pref: bool,
+    pub bright_moon: bool,
+    pub temp_pref: bool,
+    pub falling_pressure: bool,
+    pub retrieve_pref: bool,
+}
 
 #[derive(Resource)]
 pub struct ProbTimer{
@@ -41,22 +138,23 @@ pub fn calc_fish_prob(
         let mut b_a = 0.;
         let mut b = 0.;
         let current_weather = weather.weather_by_region.get(current_region.get()).unwrap();
-        if species.weather == *current_weather && (time.hour >= (species.time_of_day.0 as i32) && time.hour <= (species.time_of_day.1 as i32)) {
+        let feeding_hours = species.time_of_day.hours();
+        if species.weather == *current_weather && time.is_within(&species.time_of_day) {
             b_a = species.catch_prob;
-            b = (0.25)*(((species.time_of_day.1 as f32)-(species.time_of_day.0 as f32))/24.);
+            b = (0.25)*(feeding_hours/24.);
         }
-        else if species.weather == *current_weather || (time.hour >= (species.time_of_day.0 as i32) && time.hour <= (species.time_of_day.1 as i32)) {
+        else if species.weather == *current_weather || time.is_within(&species.time_of_day) {
             b_a = species.catch_prob/2.;
             if species.weather == *current_weather {
-                b = (0.25)*(1. - (((species.time_of_day.1 as f32)-(species.time_of_day.0 as f32))/24.));
+                b = (0.25)*(1. - (feeding_hours/24.));
             }
             else {
-                b = (0.75)*(((species.time_of_day.1 as f32)-(species.time_of_day.0 as f32))/24.);
+                b = (0.75)*(feeding_hours/24.);
             }
         }
         else{
             b_a = species.catch_prob / 4.;
-            b = (0.75)*(1. - (((species.time_of_day.1 as f32)-(species.time_of_day.0 as f32))/24.));
+            b = (0.75)*(1. - (feeding_hours/24.));
         }
 
         let mut result = (b_a*a)/b;
@@ -69,43 +167,33 @@ pub fn calc_fish_prob(
 }
 
 pub fn hook_fish(
-    mut potential_fish: (&mut Fish, &Species, &HookProbCpt),
-    weather: &Res<WeatherState>,
-    region: &Res<State<Region>>,
-    timer: &Res<GameDayTimer>,
-    mut prob_timer: &mut ResMut<ProbTimer>,
+    potential_fish: (&mut Fish, &Species, &HookProbCpt),
+    conditions: &BiteConditions,
+    prob_timer: &mut ResMut<ProbTimer>,
     time: &Res<Time>,
-    lure: &Lure,
+    presentation: &LurePresentation,
+    hook_shy: f32,
     ) -> bool {
 
         prob_timer.timer.tick(time.delta());
         if prob_timer.timer.just_finished() {
                 let (fish, species, hook_prob_cpt) = potential_fish;
-                
-                let mut t: bool = false;
-                if timer.hour >= (species.time_of_day.0 as i32) && timer.hour <= (species.time_of_day.1 as i32) {
-                    t = true;
-                }
-
-                let mut w: bool = false;
-                let current_weather = weather.weather_by_region.get(region.get()).unwrap();
-                if species.weather == *current_weather {
-                    w = true;
-                }
-
-                let mut l: bool = false; 
-                if lure.name == species.lure_pref.name {
-                    l = true;
-                }
-
-                let mut d: bool = false;
-                let lure_data = lure;
-                let lure_depth: f32 = 0.;
-                if lure.depth <= species.depth.1 as f32 && lure.depth >= species.depth.0 as f32{
-                    d = true;
-                }
-
-                let prob = 100. * hook_prob_cpt.index_cpt(true, fish.hunger, t, w, d, l);
+                let current_weather = conditions.weather.weather_by_region.get(conditions.region).unwrap();
+
+                let evidence = BiteEvidence {
+                    time_pref: conditions.timer.is_within(&species.time_of_day),
+                    weather_pref: species.weather == *current_weather,
+                    depth_pref: presentation.depth <= species.depth.1 as f32 && presentation.depth >= species.depth.0 as f32,
+                    lure_pref: presentation.lure.name == species.lure_pref.name,
+                    bright_moon: conditions.timer.bright_moon(),
+                    temp_pref: presentation.temp >= species.temp_pref.0 && presentation.temp <= species.temp_pref.1,
+                    falling_pressure: conditions.pressure.trend(conditions.region) == PressureTrend::Falling,
+                    retrieve_pref: presentation.retrieve == species.retrieve_pref,
+                };
+
+                let storm_frenzy = species.weather == Weather::Thunderstorm && *current_weather == Weather::Thunderstorm;
+                let prob = 100. * hook_prob_cpt.index_cpt(true, fish.hunger, &evidence)
+                    * after_cpt(storm_frenzy, presentation.line_seen, hook_shy);
                 println!("ok");
                 let mut prob_rng = rand::thread_rng();
                 let roll = prob_rng.gen_range(0..100);
@@ -120,13 +208,14 @@ pub fn hook_fish(
 
 #[derive(Component)]
 pub struct HungerCpt{
-    pub cpt: [f32; 64]
+    pub cpt: [f32; 128]
 }
 
 impl HungerCpt {
-    pub fn new(time_pref: (usize, usize)) -> Self{
-        let hrs_pref = time_pref.1 - time_pref.0 + 1;
-        let mut in_cpt: [f32; 64] = [0.; 64];
+    pub fn new(time_pref: TimeWindows) -> Self{
+        let hrs_pref = Self::pref_hours(&time_pref);
+        let nocturnal = time_pref.is_nocturnal();
+        let mut in_cpt: [f32; 128] = [0.; 128];
         let mut ind = 0;
         //hook ct levels
         for i in 0..4 {
@@ -152,12 +241,18 @@ impl HungerCpt {
                     //age
                     for n in 0..2{
                         let mut age_prob = 0.7 + (0.3*(n as f32));
-                        in_cpt[ind] = hook_ct_prob*time_pref_prob*weather_pref_prob*age_prob;
-                        in_cpt[32+ind] = 1. - (hook_ct_prob*time_pref_prob*weather_pref_prob*age_prob);
-                        // println!("ct prob:{}  time_prob:{}  weather_prob:{}  age_prob:{}", hook_ct_prob, time_pref_prob, weather_pref_prob, age_prob);
-                        // print!("{}\t{}", in_cpt[ind], in_cpt[32+ind]);
-                        // println!("\n");
-                        ind+=1;
+                        //moon
+                        for m in 0..2{
+                            // the time term runs over 1 for short feeding windows
+                            let prob = (hook_ct_prob*time_pref_prob*weather_pref_prob*age_prob).min(1.);
+                            let prob = given_moon(nocturnal, m == 0, prob);
+                            in_cpt[ind] = prob;
+                            in_cpt[64+ind] = 1. - prob;
+                            // println!("ct prob:{}  time_prob:{}  weather_prob:{}  age_prob:{}  prob:{}", hook_ct_prob, time_pref_prob, weather_pref_prob, age_prob, prob);
+                            // print!("{}\t{}", in_cpt[ind], in_cpt[64+ind]);
+                            // println!("\n");
+                            ind+=1;
+                        }
                     }
                 }
             }
@@ -165,21 +260,36 @@ impl HungerCpt {
         Self{cpt: in_cpt}
     }
     
-    pub fn h_t(hrs_pref: usize) -> f32{
-        let mut t = (hrs_pref as f32)/24.;
-        let mut not_t = ((24-hrs_pref) as f32)/24.;
+    // Hours covered by the feeding windows, kept away from 0 and 24 so the
+    // time terms below never divide by zero
+    pub fn pref_hours(time_pref: &TimeWindows) -> f32 {
+        time_pref.hours().clamp(0.5, 23.5)
+    }
+
+    pub fn h_t(hrs_pref: f32) -> f32{
+        let mut t = hrs_pref/24.;
+        let mut not_t = (24.-hrs_pref)/24.;
         let mut t_h = t + (not_t*BASE_HUNGER_PROB);
         return (t_h*BASE_HUNGER_PROB)/t;
     }
 
-    pub fn h_not_t(hrs_pref: usize, h_t: f32) -> f32{
-        let mut t = (hrs_pref as f32)/24.;
-        let mut not_t = ((24-hrs_pref) as f32)/24.;
+    pub fn h_not_t(hrs_pref: f32, h_t: f32) -> f32{
+        let mut t = hrs_pref/24.;
+        let mut not_t = (24.-hrs_pref)/24.;
         let mut h_and_t = h_t * t;
         return (BASE_HUNGER_PROB - h_and_t)/not_t;
     }
 
-    pub fn index_cpt(&self, inc: bool, hook_ct: i32, time_pref: bool, weather_pref: bool, age: f32) -> f32 {
+    // Chance of hunger going up this hour given the current weather
+    pub fn hunger_prob(&self, hook_ct: i32, time_pref: bool, weather_pref: bool, age: f32, bright_moon: bool, weather: &Weather) -> f32 {
+        let prob = self.index_cpt(true, hook_ct, time_pref, weather_pref, age, bright_moon);
+        if *weather == Weather::Snow {
+            return prob * SNOW_ACTIVITY;
+        }
+        return prob;
+    }
+
+    pub fn index_cpt(&self, inc: bool, hook_ct: i32, time_pref: bool, weather_pref: bool, age: f32, bright_moon: bool) -> f32 {
         let mut ind: usize = 0;
         //Looking for prob of increase or not increase?
         if inc == false {
@@ -212,6 +322,11 @@ impl HungerCpt {
             ind += AGE_OLD;
         }
 
+        //Looking for prob with/without a bright moon
+        if !bright_moon {
+            ind += MOON_NOT_BRIGHT;
+        }
+
         //Use index to get correct val to return
         return self.cpt[ind];
     }
@@ -219,14 +334,16 @@ impl HungerCpt {
 
 #[derive(Component)]
 pub struct HookProbCpt {
-    pub cpt: [f32; 320]
+    pub cpt: [f32; 5120]
 }
 
 impl HookProbCpt {
-    pub fn new(time_pref: (usize, usize), depth_pref: (i32, i32), catch_prob: f32) -> Self {
-        let hrs_pref = time_pref.1 - time_pref.0 + 1;
+    pub fn new(time_pref: TimeWindows, depth_pref: (i32, i32), temp_pref: (f32, f32), catch_prob: f32) -> Self {
+        let hrs_pref = HungerCpt::pref_hours(&time_pref);
         let depth_pref_num = depth_pref.1 - depth_pref.0 + 1;
-        let mut in_cpt: [f32; 320] = [0.; 320];
+        let temp_pref_range = (temp_pref.1 - temp_pref.0).clamp(1., TEMP_SPAN - 1.);
+        let nocturnal = time_pref.is_nocturnal();
+        let mut in_cpt: [f32; 5120] = [0.; 5120];
         let mut ind = 0;
         //For each hunger level
         for h in 1..11 {
@@ -281,12 +398,42 @@ impl HookProbCpt {
                             else{
                                 lure_pref_prob = (catch_prob-(((1./3.)*1.75)*catch_prob))/(2./3.);
                             }
-                            in_cpt[ind] = hook_hunger_prob*time_pref_prob*weather_pref_prob*depth_pref_prob*lure_pref_prob;
-                            in_cpt[160+ind] = 1. - (hook_hunger_prob*time_pref_prob*weather_pref_prob*depth_pref_prob*lure_pref_prob);
-                            // println!("hunger prob:{}  time_prob:{}  weather_prob:{}  depth_prob:{}  lure_prob:{}", hook_hunger_prob, time_pref_prob, weather_pref_prob, depth_pref_prob, lure_pref_prob);
-                            // print!("{}\t{}", in_cpt[ind], in_cpt[160+ind]);
-                            // println!("\n");
-                            ind+=1;
+                            //For moonlight
+                            for m in 0..2{
+                                //For water temperature preference
+                                for c in 0..2{
+                                    let temp_pref_prob = if c == 0 {
+                                        Self::h_c(temp_pref_range, catch_prob).min(1.)
+                                    }
+                                    else{
+                                        Self::h_not_c(temp_pref_range, Self::h_c(temp_pref_range, catch_prob), catch_prob)
+                                    };
+                                    //For a falling barometer
+                                    for p in 0..2{
+                                        //For the way the lure is being retrieved
+                                        for r in 0..2{
+                                            let prob = hook_hunger_prob*time_pref_prob*weather_pref_prob*depth_pref_prob*lure_pref_prob*temp_pref_prob;
+                                            let prob = given_moon(nocturnal, m == 0, prob);
+                                            let prob = if p == 0 {
+                                                h_e(FALLING_PRESSURE_SHARE, prob, FALLING_PRESSURE_STRENGTH)
+                                            } else {
+                                                h_not_e(FALLING_PRESSURE_SHARE, prob, FALLING_PRESSURE_STRENGTH)
+                                            };
+                                            let prob = if r == 0 {
+                                                h_e(RETRIEVE_SHARE, prob, RETRIEVE_STRENGTH)
+                                            } else {
+                                                h_not_e(RETRIEVE_SHARE, prob, RETRIEVE_STRENGTH)
+                                            };
+                                            in_cpt[ind] = prob;
+                                            in_cpt[2560+ind] = 1. - prob;
+                                            // println!("hunger prob:{}  time_prob:{}  weather_prob:{}  depth_prob:{}  lure_prob:{}  temp_prob:{}  prob:{}", hook_hunger_prob, time_pref_prob, weather_pref_prob, depth_pref_prob, lure_pref_prob, temp_pref_prob, prob);
+                                            // print!("{}\t{}", in_cpt[ind], in_cpt[2560+ind]);
+                                            // println!("\n");
+                                            ind+=1;
+                                        }
+                                    }
+                                }
+                            }
                             }
                         
                     }
@@ -296,16 +443,16 @@ impl HookProbCpt {
         Self{cpt: in_cpt}
     }
 
-    pub fn h_t(hrs_pref: usize, catch_prob: f32) -> f32{
-        let mut t = (hrs_pref as f32)/24.;
-        let mut not_t = ((24-hrs_pref) as f32)/24.;
+    pub fn h_t(hrs_pref: f32, catch_prob: f32) -> f32{
+        let mut t = hrs_pref/24.;
+        let mut not_t = (24.-hrs_pref)/24.;
         let mut t_h = t + (not_t*catch_prob);
         return (t_h*(catch_prob))/t;
     }
 
-    pub fn h_not_t(hrs_pref: usize, h_t: f32, catch_prob: f32) -> f32{
-        let mut t = (hrs_pref as f32)/24.;
-        let mut not_t = ((24-hrs_pref) as f32)/24.;
+    pub fn h_not_t(hrs_pref: f32, h_t: f32, catch_prob: f32) -> f32{
+        let mut t = hrs_pref/24.;
+        let mut not_t = (24.-hrs_pref)/24.;
         let mut h_and_t = h_t * t;
         return (catch_prob - h_and_t)/not_t;
     }
@@ -324,17 +471,35 @@ impl HookProbCpt {
         return (catch_prob - h_and_d)/not_d;
     }
 
-    pub fn index_cpt(&self, inc: bool, hunger_score: f32, time_pref: bool, weather_pref: bool, depth_pref: bool, lure_pref: bool) -> f32 {
-        const HOOK_IND: usize = 160;
-        const HUNGER_PT: usize = 16;
-        const TIME_IND: usize = 8;
-        const WEATHER_IND: usize = 4;
-        const DEPTH_IND: usize = 2;
-        const LURE_IND: usize = 1;
+    pub fn h_c(temp_pref: f32, catch_prob: f32) -> f32 {
+        let c = temp_pref/TEMP_SPAN;
+        let not_c = (TEMP_SPAN-temp_pref)/TEMP_SPAN;
+        let c_h = c + (not_c*catch_prob);
+        return (c_h*catch_prob)/c;
+    }
+
+    pub fn h_not_c(temp_pref: f32, h_c: f32, catch_prob: f32) -> f32{
+        let c = temp_pref/TEMP_SPAN;
+        let not_c = (TEMP_SPAN-temp_pref)/TEMP_SPAN;
+        let h_and_c = h_c * c;
+        return (catch_prob - h_and_c)/not_c;
+    }
+
+    pub fn index_cpt(&self, inc: bool, hunger_score: f32, evidence: &BiteEvidence) -> f32 {
+        const HOOK_IND: usize = 2560;
+        const HUNGER_PT: usize = 256;
+        const TIME_IND: usize = 128;
+        const WEATHER_IND: usize = 64;
+        const DEPTH_IND: usize = 32;
+        const LURE_IND: usize = 16;
+        const MOON_IND: usize = 8;
+        const TEMP_IND: usize = 4;
+        const PRESSURE_IND: usize = 2;
+        const RETRIEVE_IND: usize = 1;
 
         let mut ind: usize = 0;
         //Looking for prob of increase or not increase?
-        if inc == false {
+        if !inc {
             ind += HOOK_IND;
         }
 
@@ -346,26 +511,46 @@ impl HookProbCpt {
         }
 
         //Looking for prob with pref/not pref time
-        if time_pref == false {
+        if !evidence.time_pref {
             ind += TIME_IND;
         }
 
         //Looking for prob with pref/not pref weather
-        if weather_pref == false {
+        if !evidence.weather_pref {
             ind += WEATHER_IND;
         }
 
         //Looking for prob with pref/not pref depth
-        if depth_pref == false {
+        if !evidence.depth_pref {
             ind += DEPTH_IND;
         }
 
         //Looking for prob with pref/not pref lure
-        if lure_pref == false {
+        if !evidence.lure_pref {
             ind += LURE_IND;
         }
 
-        //println!("Indexing cpt...\nHunger: {}\tTime Pref?:{}\tWeather Pref?:{}\tDepth Pref?{}\tLure Pref?{}\nIndex:{}\tProb:{}", hunger_score, time_pref, weather_pref, depth_pref, lure_pref, ind, self.cpt[ind]);
+        //Looking for prob with/without a bright moon
+        if !evidence.bright_moon {
+            ind += MOON_IND;
+        }
+
+        //Looking for prob with pref/not pref water temperature
+        if !evidence.temp_pref {
+            ind += TEMP_IND;
+        }
+
+        //Looking for prob with/without a falling barometer
+        if !evidence.falling_pressure {
+            ind += PRESSURE_IND;
+        }
+
+        //Looking for prob with pref/not pref retrieve
+        if !evidence.retrieve_pref {
+            ind += RETRIEVE_IND;
+        }
+
+        //println!("Indexing cpt...\nHunger: {}\tTime Pref?:{}\tWeather Pref?:{}\tDepth Pref?{}\tLure Pref?{}\nIndex:{}\tProb:{}", hunger_score, evidence.time_pref, evidence.weather_pref, evidence.depth_pref, evidence.lure_pref, ind, self.cpt[ind]);
         //Use index to get correct val to return
         return self.cpt[ind];
     }
@@ -10,6 +10,22 @@ pub const SHOP_CENTER: Vec2 = Map::get_area_center(1, -2);
 pub const SHOP_X: f32 = SHOP_CENTER.x;
 pub const SHOP_Y: f32 = SHOP_CENTER.y;
 
+const NEXT_PAGE: KeyCode = KeyCode::Tab;
+const SELL_JUNK: KeyCode = KeyCode::KeyR;
+// Coins the shop gives for recycling junk that's otherwise worthless
+const RECYCLE_VALUE: u32 = 1;
+const SLOTS_PER_PAGE: usize = 6;
+
+//slot positions
+const SLOT_POSITIONS: [Vec3; SLOTS_PER_PAGE] = [
+    Vec3::new(SHOP_X - 380., SHOP_Y - 180., 2.),
+    Vec3::new(SHOP_X, SHOP_Y - 180., 2.),
+    Vec3::new(SHOP_X + 400., SHOP_Y - 180., 2.),
+    Vec3::new(SHOP_X - 380., SHOP_Y + 100., 2.),
+    Vec3::new(SHOP_X, SHOP_Y + 100., 2.),
+    Vec3::new(SHOP_X + 400., SHOP_Y + 100., 2.),
+];
+
 #[derive(Component)]
 struct ShopEntrance;
 
@@ -22,6 +38,7 @@ pub enum ItemType {
     LINE,
     LURE,
     COSMETIC,
+    TOOL,
 }
 
 #[derive(Component, Clone)]
@@ -56,8 +73,16 @@ struct SelectedShopItem {
     index: usize,
 }
 
+// Sold marker for the shop item entity it covers
+#[derive(Component)]
+struct SoldSprite(Entity);
+
+// Which page of the shop an item is shown on
+#[derive(Component)]
+struct ShopPage(usize);
+
 #[derive(Component)]
-struct SoldSprite;
+struct ShopPageLabel;
 
 pub struct ShopPlugin;
 impl Plugin for ShopPlugin {
@@ -70,7 +95,7 @@ impl Plugin for ShopPlugin {
             )
             .add_systems(
                 Update,
-                (handle_purchase, update_selected_item, exit_shop)
+                (handle_purchase, sell_junk, update_selected_item, show_shop_page, exit_shop)
                     .run_if(in_state(CurrentInterface::Shop)),
             )
             .add_systems(
@@ -101,6 +126,8 @@ fn setup_player_inventory(mut commands: Commands) {
             ItemType::LINE,
         )]),
         cosmetics: Vec::new(),
+        tools: Vec::new(),
+        junk: Vec::new(),
         rod_index: 0,
         lure_index: 0,
         line_index: 0,
@@ -173,6 +200,36 @@ fn spawn_shop(asset_server: Res<AssetServer>, mut commands: Commands) {
         index: 0,
         item_type: ItemType::COSMETIC,
     });
+    commands.spawn(ShopItem {
+        name: "Thermometer",
+        is_bought: false,
+        price: 40,
+        index: 0,
+        item_type: ItemType::TOOL,
+    });
+    commands.spawn(ShopItem {
+        name: "Barometer",
+        is_bought: false,
+        price: 60,
+        index: 0,
+        item_type: ItemType::TOOL,
+    });
+
+    commands.spawn((
+        Text2dBundle {
+            text: Text::from_section(
+                "",
+                TextStyle {
+                    font: asset_server.load("fonts/pixel.ttf"),
+                    font_size: 30.0,
+                    color: Color::WHITE,
+                },
+            ),
+            transform: Transform::from_xyz(SHOP_X, SHOP_Y - 330., 3.),
+            ..default()
+        },
+        ShopPageLabel,
+    ));
 
     let hover_texture = asset_server.load("shop/hover.png");
     let hover_entity = commands
@@ -193,6 +250,7 @@ fn display_shop_items(
     mut commands: Commands,
     shop_items: Query<(Entity, &ShopItem)>,
     asset_server: Res<AssetServer>,
+    selected_item: Res<SelectedShopItem>,
 ) {
     let swim_bait_texture = asset_server.load("lures/swim_bait.png");
     let frog_bait_texture = asset_server.load("lures/frog_bait.png");
@@ -200,22 +258,22 @@ fn display_shop_items(
     let monofil_texture = asset_server.load("lines/monofilament.png");
     let braided_line_texture = asset_server.load("lines/braided.png");
     let glasses_texture = asset_server.load("shop/polarized_glasses.png");
+    let thermometer_texture = asset_server.load("shop/thermometer.png");
+    let barometer_texture = asset_server.load("shop/barometer.png");
     let sold_texture: Handle<Image> = asset_server.load("shop/sold.png");
 
-    //slot positions
-    let slot_positions = [
-        Vec3::new(SHOP_X - 380., SHOP_Y - 180., 2.),
-        Vec3::new(SHOP_X, SHOP_Y - 180., 2.),
-        Vec3::new(SHOP_X + 400., SHOP_Y - 180., 2.),
-        Vec3::new(SHOP_X - 380., SHOP_Y + 100., 2.),
-        Vec3::new(SHOP_X, SHOP_Y + 100., 2.),
-        Vec3::new(SHOP_X + 400., SHOP_Y + 100., 2.),
-    ];
-
     let font: Handle<Font> = asset_server.load("fonts/pixel.ttf");
+    let current_page = selected_item.index / SLOTS_PER_PAGE;
 
     for (i, (entity, item)) in shop_items.iter().enumerate() {
-        if let Some(&position) = slot_positions.get(i) {
+        let page = i / SLOTS_PER_PAGE;
+        let visibility = if page == current_page {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+
+        if let Some(&position) = SLOT_POSITIONS.get(i % SLOTS_PER_PAGE) {
             let mut position_sold = position;
             position_sold.z += 1 as f32;
             position_sold.y += 30 as f32;
@@ -226,6 +284,8 @@ fn display_shop_items(
                 "FluoroCarbon Fishing Line" => monofil_texture.clone(),
                 "Braided Fishing Line" => braided_line_texture.clone(),
                 "Polarized Sun Glasses" => glasses_texture.clone(),
+                "Thermometer" => thermometer_texture.clone(),
+                "Barometer" => barometer_texture.clone(),
                 _ => {
                     println!("No texture found for item: {}", item.name);
                     continue;
@@ -234,11 +294,15 @@ fn display_shop_items(
 
             commands
                 .entity(entity)
-                .insert(SpriteBundle {
-                    texture,
-                    transform: Transform::from_translation(position),
-                    ..Default::default()
-                })
+                .insert((
+                    SpriteBundle {
+                        texture,
+                        transform: Transform::from_translation(position),
+                        visibility,
+                        ..Default::default()
+                    },
+                    ShopPage(page),
+                ))
                 .with_children(|parent| {
                     parent.spawn(SpriteBundle {
                         texture: sold_texture.clone(),
@@ -281,7 +345,8 @@ fn display_shop_items(
                     visibility: Visibility::Hidden,
                     ..Default::default()
                 },
-                SoldSprite,
+                SoldSprite(entity),
+                ShopPage(page),
             ));
         } else {
             println!("No available slots");
@@ -318,7 +383,7 @@ fn check_shop_entrance(
     {
         return;
     } else {
-        if *pd == PlayerDirection::Back && time_of_day.hour < 21 {
+        if *pd == PlayerDirection::Back && time_of_day.hour() < 21 {
             let mut camera = camera_query.single_mut();
             original_camera_pos.position = camera.translation;
             let new_position = Vec3::new(SHOP_X, SHOP_Y, camera.translation.z);
@@ -345,16 +410,7 @@ fn handle_purchase(
             if let Some(mut shop_item) = shop_items.iter_mut().nth(selected_item.index) {
                 if inventory.coins >= shop_item.price && !shop_item.is_bought {
                     inventory.coins -= shop_item.price;
-                    inventory.items.push(shop_item.clone());
-
-                    let category = match shop_item.item_type {
-                        ItemType::ROD => &mut inventory.rods,
-                        ItemType::LURE => &mut inventory.lures,
-                        ItemType::LINE => &mut inventory.lines,
-                        ItemType::COSMETIC => &mut inventory.cosmetics,
-                    };
-
-                    category.push(shop_item.clone());
+                    inventory.add_item(&shop_item);
 
                     shop_item.is_bought = true;
 
@@ -374,6 +430,37 @@ fn handle_purchase(
     }
 }
 
+// Sells everything reeled in that wasn't a fish, worthless junk is recycled
+fn sell_junk(
+    keyboard_input: Res<ButtonInput<KeyCode>>,
+    mut player_inventory: Query<&mut PlayerInventory>,
+) {
+    if !keyboard_input.just_pressed(SELL_JUNK) {
+        return;
+    }
+
+    let Ok(mut inventory) = player_inventory.get_single_mut() else {
+        return;
+    };
+
+    if inventory.junk.is_empty() {
+        println!("No junk to sell");
+        return;
+    }
+
+    let junk = std::mem::take(&mut inventory.junk);
+    let sold: u32 = junk.iter().map(|item| item.value).sum();
+    let recycled = junk.iter().filter(|item| item.value == 0).count() as u32;
+
+    inventory.coins += sold + recycled * RECYCLE_VALUE;
+    println!(
+        "Sold junk for {} coins, recycled {} pieces for {} coins",
+        sold,
+        recycled,
+        recycled * RECYCLE_VALUE
+    );
+}
+
 fn update_selected_item(
     input: Res<ButtonInput<KeyCode>>,
     mut selected_item: ResMut<SelectedShopItem>,
@@ -383,9 +470,13 @@ fn update_selected_item(
 ) {
     let cols = 3;
     let rows = 2;
+    let pages = shop_items.iter().count().div_ceil(SLOTS_PER_PAGE);
 
-    let current_row = selected_item.index / cols;
-    let current_col = selected_item.index % cols;
+    let current_page = selected_item.index / SLOTS_PER_PAGE;
+    let slot = selected_item.index % SLOTS_PER_PAGE;
+    let current_row = slot / cols;
+    let current_col = slot % cols;
+    let page_start = current_page * SLOTS_PER_PAGE;
 
     if input.just_pressed(KeyCode::ArrowUp) {
         let new_row = if current_row == 0 {
@@ -393,7 +484,7 @@ fn update_selected_item(
         } else {
             current_row - 1
         };
-        selected_item.index = new_row * cols + current_col;
+        selected_item.index = page_start + new_row * cols + current_col;
         println!("Selected: {}", selected_item.index);
     }
 
@@ -403,7 +494,7 @@ fn update_selected_item(
         } else {
             current_row + 1
         };
-        selected_item.index = new_row * cols + current_col;
+        selected_item.index = page_start + new_row * cols + current_col;
         println!("Selected: {}", selected_item.index);
     }
 
@@ -413,7 +504,7 @@ fn update_selected_item(
         } else {
             current_col - 1
         };
-        selected_item.index = current_row * cols + new_col;
+        selected_item.index = page_start + current_row * cols + new_col;
         println!("Selected: {}", selected_item.index);
     }
 
@@ -423,21 +514,17 @@ fn update_selected_item(
         } else {
             current_col + 1
         };
-        selected_item.index = current_row * cols + new_col;
+        selected_item.index = page_start + current_row * cols + new_col;
         println!("Selected: {}", selected_item.index);
     }
 
-    // Define slot positions
-    let slot_positions = [
-        Vec3::new(SHOP_X - 380., SHOP_Y - 180., 2.),
-        Vec3::new(SHOP_X, SHOP_Y - 180., 2.),
-        Vec3::new(SHOP_X + 400., SHOP_Y - 180., 2.),
-        Vec3::new(SHOP_X - 380., SHOP_Y + 100., 2.),
-        Vec3::new(SHOP_X, SHOP_Y + 100., 2.),
-        Vec3::new(SHOP_X + 400., SHOP_Y + 100., 2.),
-    ];
-
-    if let Some(&position) = slot_positions.get(selected_item.index) {
+    // Flip to the next page, keeping the same slot selected
+    if input.just_pressed(NEXT_PAGE) && pages > 1 {
+        selected_item.index = ((current_page + 1) % pages) * SLOTS_PER_PAGE + slot;
+        println!("Shop page: {}", selected_item.index / SLOTS_PER_PAGE + 1);
+    }
+
+    if let Some(&position) = SLOT_POSITIONS.get(selected_item.index % SLOTS_PER_PAGE) {
         if let Ok(mut hover_transform) = hover_query.get_mut(hover_entity.0) {
             let adjusted_x = if position == Vec3::new(SHOP_X, SHOP_Y - 180., 2.)
                 || position == Vec3::new(SHOP_X, SHOP_Y + 100., 2.)
@@ -453,6 +540,49 @@ fn update_selected_item(
     }
 }
 
+// Only the items on the selected page are shown
+fn show_shop_page(
+    selected_item: Res<SelectedShopItem>,
+    mut shop_items: Query<(&ShopItem, &ShopPage, &mut Visibility), Without<SoldSprite>>,
+    mut sold_sprites: Query<(&SoldSprite, &ShopPage, &mut Visibility), Without<ShopItem>>,
+    mut page_label: Query<&mut Text, With<ShopPageLabel>>,
+    player_inventory: Query<&PlayerInventory>,
+) {
+    let current_page = selected_item.index / SLOTS_PER_PAGE;
+    let mut pages = 1;
+
+    for (_item, page, mut visibility) in shop_items.iter_mut() {
+        pages = pages.max(page.0 + 1);
+        *visibility = if page.0 == current_page {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    for (sold, page, mut visibility) in sold_sprites.iter_mut() {
+        let is_bought = shop_items
+            .get(sold.0)
+            .is_ok_and(|(item, _, _)| item.is_bought);
+
+        *visibility = if page.0 == current_page && is_bought {
+            Visibility::Visible
+        } else {
+            Visibility::Hidden
+        };
+    }
+
+    if let Ok(mut text) = page_label.get_single_mut() {
+        let junk = player_inventory.get_single().map_or(0, |inventory| inventory.junk.len());
+        text.sections[0].value = format!(
+            "Page {}/{}  [Tab] next page  [R] sell junk ({})",
+            current_page + 1,
+            pages,
+            junk
+        );
+    }
+}
+
 fn exit_shop(
     input: Res<ButtonInput<KeyCode>>,
     mut next_interface: ResMut<NextState<CurrentInterface>>,
@@ -0,0 +1,250 @@
+use bevy::prelude::*;
+use rand::Rng;
+
+use crate::bottom::*;
+use crate::fish::*;
+use crate::fishing_view::*;
+use crate::physics::*;
+use crate::tide::*;
+
+const REEL: KeyCode = KeyCode::KeyO;
+
+// Metres off the bottom that still count as dragging along it
+const BOTTOM_CONTACT: f32 = 0.5;
+// Chance per second of reeling along the bottom that the lure catches on something
+const BOTTOM_SNAG_CHANCE: f32 = 0.25;
+// Pixels from the middle of a pad, fissure or tree the line can catch on it
+const SNAG_RADIUS: f32 = 50.;
+// Lily pad stems only reach this far under the surface
+const PAD_STEM_DEPTH: f32 = 3.;
+// A fissure only catches lures down near the bottom
+const FISSURE_DEPTH: f32 = 5.;
+// Seconds of pulling on a snag before the line gives and it's broken off
+const SNAG_BREAK_TIME: f32 = 4.;
+// A wrapped line rubs on the structure even when nobody is reeling
+const WRAP_BREAK_TIME: f32 = 6.;
+// Radians of rod waggling it takes to work a snag loose, least and most
+const JIGGLE_MIN: f32 = 1.;
+const JIGGLE_MAX: f32 = 4.;
+
+// Chance per second of dragging the lure through each kind of structure that it snags
+fn snag_chance(obst_type: ObstType) -> f32 {
+    match obst_type {
+        ObstType::Pad => 0.6,
+        ObstType::Fissure => 0.8,
+        ObstType::Tree => 0.9,
+        ObstType::Debris => 0.,
+    }
+}
+
+// Chance per second a hooked fish swimming through structure wraps the line around it
+fn wrap_chance(obst_type: ObstType) -> f32 {
+    match obst_type {
+        ObstType::Pad => 0.3,
+        ObstType::Fissure => 0.5,
+        ObstType::Tree => 0.6,
+        ObstType::Debris => 0.,
+    }
+}
+
+pub enum TangleResult {
+    Holding,
+    Free,
+    Broken,
+}
+
+// Line caught on something. Waggling the rod works it loose, reeling against
+// it wears the line until it snaps.
+pub struct Tangle {
+    pub strain: f32,
+    pub jiggle: f32,
+    pub free_at: f32,
+    pub last_rotation: f32,
+}
+
+impl Tangle {
+    pub fn new(rod_rotation: f32) -> Self {
+        Self {
+            strain: 0.,
+            jiggle: 0.,
+            free_at: rand::thread_rng().gen_range(JIGGLE_MIN..JIGGLE_MAX),
+            last_rotation: rod_rotation,
+        }
+    }
+
+    pub fn work(&mut self, rod_rotation: f32, strain: f32, break_time: f32) -> TangleResult {
+        self.jiggle += (rod_rotation - self.last_rotation).abs();
+        self.last_rotation = rod_rotation;
+        self.strain += strain;
+
+        if self.jiggle >= self.free_at {
+            TangleResult::Free
+        } else if self.strain > break_time {
+            TangleResult::Broken
+        } else {
+            TangleResult::Holding
+        }
+    }
+}
+
+// Lure caught on the bottom or on structure, it stays put until it's
+// jiggled free or broken off
+#[derive(Component)]
+pub struct Snagged {
+    pub hold: Vec3,
+    pub tangle: Tangle,
+}
+
+// Hooked fish that has swum the line around structure, the line now pulls
+// from there instead of from the rod
+#[derive(Component)]
+pub struct Wrapped {
+    pub around: Vec3,
+    pub tangle: Tangle,
+}
+
+// Whether a lure at this position would be in amongst the structure
+fn in_structure(obst_type: ObstType, obst_pos: Vec3, lure_pos: Vec3, floor: f32) -> bool {
+    if obst_pos.truncate().distance(lure_pos.truncate()) > SNAG_RADIUS {
+        return false;
+    }
+
+    match obst_type {
+        ObstType::Pad => lure_pos.z > -PAD_STEM_DEPTH * DEPTH_UNITS_PER_METER,
+        ObstType::Fissure => lure_pos.z < floor + FISSURE_DEPTH * DEPTH_UNITS_PER_METER,
+        ObstType::Tree => true,
+        ObstType::Debris => false,
+    }
+}
+
+pub fn snag_lure(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    bottom: Res<Bottom>,
+    tide: Res<Tide>,
+    fishing_location: Res<State<FishingLocal>>,
+    mut next_state: ResMut<NextState<FishingState>>,
+    rod: Query<&FishingRod>,
+    obstacles: Query<(&Transform, &ObstType, &FishingLocal), With<PondObstruction>>,
+    mut lure: Query<(Entity, &mut PhysicsObject, Option<&mut Snagged>), (With<Lure>, With<Hooked>)>,
+) {
+    let Ok((entity_id, mut lure_physics, snagged)) = lure.get_single_mut() else {
+        return;
+    };
+
+    let mut rng = rand::thread_rng();
+    let rod_info = rod.single();
+    let reeling = input.pressed(REEL);
+    let dt = time.delta_seconds();
+
+    let Some(mut snag) = snagged else {
+        if !reeling {
+            return;
+        }
+
+        let local = fishing_location.get();
+        let floor = bottom.floor_z(local, lure_physics.position, &tide);
+        let on_bottom = lure_physics.position.z <= floor + BOTTOM_CONTACT * DEPTH_UNITS_PER_METER;
+
+        let mut chances: Vec<(f32, &str)> = Vec::new();
+        if on_bottom {
+            chances.push((BOTTOM_SNAG_CHANCE, "the bottom"));
+        }
+        for (obst_transform, obst_type, obst_local) in obstacles.iter() {
+            if obst_local == local && in_structure(*obst_type, obst_transform.translation, lure_physics.position, floor) {
+                chances.push((snag_chance(*obst_type), obst_type.name()));
+            }
+        }
+
+        for (chance, what) in chances {
+            if rng.gen::<f32>() < chance * dt {
+                println!("Snagged on {}! Wiggle the rod to work it free", what);
+                commands.entity(entity_id).insert(Snagged {
+                    hold: lure_physics.position,
+                    tangle: Tangle::new(rod_info.rotation),
+                });
+                break;
+            }
+        }
+        return;
+    };
+
+    lure_physics.position = snag.hold;
+    lure_physics.velocity = Vec3::ZERO;
+
+    let strain = if reeling { dt } else { 0. };
+    match snag.tangle.work(rod_info.rotation, strain, SNAG_BREAK_TIME) {
+        TangleResult::Holding => {}
+        TangleResult::Free => {
+            println!("Jiggled the lure free");
+            commands.entity(entity_id).remove::<Snagged>();
+        }
+        TangleResult::Broken => {
+            println!("The line snapped on the snag");
+            commands.entity(entity_id).remove::<(Snagged, Hooked)>();
+            next_state.set(FishingState::Idle);
+        }
+    }
+}
+
+pub fn wrap_line(
+    mut commands: Commands,
+    input: Res<ButtonInput<KeyCode>>,
+    time: Res<Time>,
+    fishing_location: Res<State<FishingLocal>>,
+    mut next_state: ResMut<NextState<FishingState>>,
+    rod: Query<&FishingRod>,
+    obstacles: Query<(&Transform, &ObstType, &FishingLocal), With<PondObstruction>>,
+    mut fish: Query<(Entity, &mut PhysicsObject, Option<&mut Wrapped>), (With<Fish>, With<Hooked>)>,
+) {
+    let Ok((entity_id, mut fish_physics, wrapped)) = fish.get_single_mut() else {
+        return;
+    };
+
+    let mut rng = rand::thread_rng();
+    let rod_info = rod.single();
+    let dt = time.delta_seconds();
+
+    let Some(mut wrap) = wrapped else {
+        for (obst_transform, obst_type, obst_local) in obstacles.iter() {
+            let near = obst_transform.translation.truncate().distance(fish_physics.position.truncate()) < SNAG_RADIUS;
+
+            if obst_local == fishing_location.get() && near && rng.gen::<f32>() < wrap_chance(*obst_type) * dt {
+                println!("The fish wrapped the line around {}!", obst_type.name());
+                commands.entity(entity_id).insert(Wrapped {
+                    around: obst_transform.translation.with_z(fish_physics.position.z),
+                    tangle: Tangle::new(rod_info.rotation),
+                });
+                break;
+            }
+        }
+        return;
+    };
+
+    // Reeling against the structure wears the line twice as fast
+    let strain = if input.pressed(REEL) { 2. * dt } else { dt };
+    match wrap.tangle.work(rod_info.rotation, strain, WRAP_BREAK_TIME) {
+        TangleResult::Holding => {}
+        TangleResult::Free => {
+            println!("Unwrapped the line");
+            commands.entity(entity_id).remove::<Wrapped>();
+        }
+        TangleResult::Broken => {
+            println!("The line frayed through on the structure");
+            commands.entity(entity_id).remove::<(Wrapped, Hooked)>();
+            fish_physics.forces.player = Vec3::ZERO;
+            next_state.set(FishingState::Idle);
+        }
+    }
+}
+
+// A snag or wrap doesn't carry over to the next cast
+pub fn clear_snag(
+    mut commands: Commands,
+    snagged: Query<Entity, Or<(With<Snagged>, With<Wrapped>)>>,
+) {
+    for entity_id in snagged.iter() {
+        commands.entity(entity_id).remove::<(Snagged, Wrapped)>();
+    }
+}
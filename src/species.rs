@@ -1,4 +1,5 @@
 use bevy::prelude::*;
+use crate::gameday::*;
 use crate::weather::*;
 use crate::fishing_view::*;
 
@@ -21,10 +22,12 @@ pub struct Species{
     pub width: (f32, f32),
     pub weight: (f32, f32),
     pub cd: (f32, f32),
-    pub time_of_day: (usize, usize),
+    pub time_of_day: TimeWindows,
     pub weather: Weather,
     //bounds
     pub depth: (i32, i32),
+    //preferred water temperature in degrees C
+    pub temp_pref: (f32, f32),
     //x, y, z
     pub position: (i32, i32),
     //length, width, depth
@@ -32,7 +35,13 @@ pub struct Species{
     pub catch_prob: f32,
     pub obj_pref: (ObstType, i32),
     pub behavior: Behavior,
-    pub lure_pref: Lure
+    pub lure_pref: Lure,
+    // How it likes a lure to move
+    pub retrieve_pref: RetrieveStyle,
+    // Line tension the hook tears out of its mouth at. Reeling in a fish that
+    // isn't pulling peaks around 750, so soft mouths only give when you reel
+    // against a hard run.
+    pub mouth_strength: f32
 }
 
 impl Species {
@@ -43,15 +52,18 @@ impl Species {
         in_width: (f32, f32), 
         in_weight: (f32,f32), 
         in_cd: (f32, f32),
-        in_tod: (usize, usize), 
+        in_tod: TimeWindows, 
         in_weather: Weather, 
         in_depth: (i32, i32),
+        in_temp: (f32, f32),
         in_position: (i32, i32),
         in_bounds: (i32, i32),
         in_catch_prob: f32,
         in_obj_pref: (ObstType, i32),
         in_behavior: Behavior,
-        in_lure: Lure) -> Self{
+        in_lure: Lure,
+        in_retrieve: RetrieveStyle,
+        in_mouth: f32) -> Self{
             
 
             Self{
@@ -64,12 +76,15 @@ impl Species {
                 time_of_day: in_tod,
                 weather: in_weather,
                 depth: in_depth,
+                temp_pref: in_temp,
                 position: in_position,
                 bounds: in_bounds,
                 catch_prob: in_catch_prob,
                 obj_pref: in_obj_pref,
                 behavior: in_behavior,
-                lure_pref: in_lure
+                lure_pref: in_lure,
+                retrieve_pref: in_retrieve,
+                mouth_strength: in_mouth
             }
     }
 }
@@ -119,15 +134,18 @@ pub const BASS: Species = Species::new(
     (5.,7.), 
     (20.,40.), 
     (0.06, 0.94),
-    (0,22),
+    TimeWindows(&[TimeWindow::new(0., 23.)]),
     Weather::Sunny, 
     (0,20),
+    (18., 27.),
     (FISHING_ROOM_X as i32 + 90, FISHING_ROOM_Y as i32 + 50),
     (10,10),
     0.5,
     (ObstType::Pad, 2),
     Behavior::Evasive,
     Lure::BOBBER,
+    RetrieveStyle::Twitch,
+    1500.,
 );
 
 //Catfish
@@ -138,15 +156,18 @@ pub const CATFISH: Species = Species::new(
     (10.,12.), 
     (50., 70.), 
     (0.05, 0.89), 
-    (0,18),
+    TimeWindows(&[TimeWindow::new(0., 19.)]),
     Weather::Rainy, 
     (20,40),
+    (21., 29.),
     (FISHING_ROOM_X as i32, FISHING_ROOM_Y as i32 + 120),
     (5, 4),
     0.4,
     (ObstType::Fissure, 1),
     Behavior::Aggressive,
     Lure::FROG,
+    RetrieveStyle::Jig,
+    2500.,
 );
 
 //Tuna
@@ -157,15 +178,18 @@ pub const TUNA: Species = Species::new(
     (90., 130.),
     (90., 230.),
     (0.37, 0.95),
-    (1, 7),
+    TimeWindows(&[TimeWindow::new(1., 8.)]),
     Weather::Thunderstorm,
     (5, 20),
+    (15., 24.),
     (FISHING_ROOM_X as i32, FISHING_ROOM_Y as i32 + 120),
     (5,4),
     0.5,
     (ObstType::Pad, 2),
     Behavior::Passive,
     Lure::BOBBER,
+    RetrieveStyle::Steady,
+    1200.,
 );
 
 //Mahi-mahi
@@ -176,15 +200,18 @@ pub const MAHIMAHI: Species = Species::new(
     (20., 30.),
     (80., 130.),
     (0.27, 0.95),
-    (9, 18),
+    TimeWindows(&[TimeWindow::new(9., 19.)]),
     Weather::Thunderstorm,
     (25, 200),
+    (21., 30.),
     (FISHING_ROOM_X as i32, FISHING_ROOM_Y as i32 + 120),
     (5,4),
     0.4,
     (ObstType::Fissure, 1),
     Behavior::Aggressive,
     Lure::FISH,
+    RetrieveStyle::Steady,
+    900.,
 );
 
 //Swordfish
@@ -195,16 +222,20 @@ pub const SWORDFISH: Species = Species::new(
     (50., 70.),
     (60., 100.),
     (0.17, 0.95),
-    (18, 24),
+    // dusk until just past midnight
+    TimeWindows(&[TimeWindow::new(18., 1.)]),
     //is sunny just clear at night?
     Weather::Sunny,
     (100, 200),
+    (8., 18.),
     (FISHING_ROOM_X as i32, FISHING_ROOM_Y as i32 + 120),
     (5,4),
     0.4,
     (ObstType::Fissure, 3),
     Behavior::Evasive,
     Lure::FISH,
+    RetrieveStyle::Jig,
+    2000.,
 );
 
 //Red Handfsih
@@ -216,14 +247,17 @@ pub const REDHANDFISH: Species = Species::new(
     (5., 7.),
     (60., 100.),
     (0.06, 0.12),
-    (20, 21),
+    TimeWindows(&[TimeWindow::new(20., 22.)]),
     //is sunny just clear at night?
     Weather::Sunny,
     (148, 150),
+    (6., 12.),
     (FISHING_ROOM_X as i32, FISHING_ROOM_Y as i32 + 120),
     (5,4),
     0.1,
     (ObstType::Fissure, 0),
     Behavior::Elusive,
     Lure::FROG,
+    RetrieveStyle::Still,
+    1000.,
 );
\ No newline at end of file
@@ -0,0 +1,129 @@
+use bevy::prelude::*;
+use std::f32::consts::PI;
+
+use crate::fishing_view::*;
+use crate::gameday::*;
+
+// Hours between one high tide and the next
+const TIDE_PERIOD_HOURS: f32 = 12.42;
+// Metres the water rises above and falls below its mean level at spring tide
+const TIDE_RANGE: f32 = 4.;
+// Share of the full range left at neap tide, around the quarter moons
+const NEAP_RANGE: f32 = 0.6;
+// Pixels the shoreline moves per metre of tide
+const SHORE_SHIFT_PER_METER: f32 = 10.;
+// Where the water meets the sand in the beach view, relative to the room centre
+const SHORELINE_Y: f32 = -240.;
+const BEACH_WIDTH: f32 = 1072.;
+
+const WATER_COLOR: Color = Color::srgb(0.23, 0.51, 0.91);
+const SAND_COLOR: Color = Color::srgb(0.89, 0.78, 0.52);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TidePhase {
+    Rising,
+    High,
+    Falling,
+    Low,
+}
+
+// Ocean tide, height in metres from mean sea level and flow from -1 (ebbing
+// fastest) to 1 (flooding fastest)
+#[derive(Resource, Default)]
+pub struct Tide {
+    pub height: f32,
+    pub flow: f32,
+}
+
+impl Tide {
+    pub fn at(hours: f32, moon: MoonPhase) -> Self {
+        let angle = 2. * PI * hours / TIDE_PERIOD_HOURS;
+        // Spring tides at new and full moon, neap tides in between
+        let spring = NEAP_RANGE + (1. - NEAP_RANGE) * (2. * moon.illumination() - 1.).abs();
+
+        Self {
+            height: TIDE_RANGE * spring * angle.cos(),
+            flow: -angle.sin(),
+        }
+    }
+
+    pub fn phase(&self) -> TidePhase {
+        if self.flow.abs() < 0.3 {
+            if self.height > 0. {
+                TidePhase::High
+            } else {
+                TidePhase::Low
+            }
+        } else if self.flow > 0. {
+            TidePhase::Rising
+        } else {
+            TidePhase::Falling
+        }
+    }
+
+    // Extra water over the ocean spot, the ponds have no tide
+    pub fn depth_at(&self, local: &FishingLocal) -> f32 {
+        if *local == FishingLocal::Ocean {
+            self.height
+        } else {
+            0.
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct TideShoreline;
+
+pub fn update_tide(timer: Res<GameDayTimer>, mut tide: ResMut<Tide>) {
+    let hours = timer.day() as f32 * 24. + timer.hours();
+    let next = Tide::at(hours, timer.moon_phase());
+
+    if next.phase() != tide.phase() {
+        println!("The tide is now {:?}", next.phase());
+    }
+
+    *tide = next;
+}
+
+pub fn spawn_tide_shoreline(mut commands: Commands) {
+    commands.spawn((
+        SpriteBundle {
+            sprite: Sprite {
+                color: WATER_COLOR,
+                custom_size: Some(Vec2::new(BEACH_WIDTH, 0.)),
+                ..default()
+            },
+            transform: Transform::from_xyz(
+                FISHING_ROOM_X - 640. + BEACH_WIDTH / 2.,
+                FISHING_ROOM_Y + SHORELINE_Y,
+                900.5,
+            ),
+            visibility: Visibility::Hidden,
+            ..default()
+        },
+        TideShoreline,
+    ));
+}
+
+// High tide floods a strip of the beach, low tide uncovers a strip of sand
+pub fn update_tide_shoreline(
+    tide: Res<Tide>,
+    fishing_location: Res<State<FishingLocal>>,
+    mut shoreline: Query<(&mut Transform, &mut Sprite, &mut Visibility), With<TideShoreline>>,
+) {
+    let Ok((mut transform, mut sprite, mut visibility)) = shoreline.get_single_mut() else {
+        return;
+    };
+
+    if !fishing_location.eq(&FishingLocal::Ocean) {
+        *visibility = Visibility::Hidden;
+        return;
+    }
+
+    let shift = tide.height * SHORE_SHIFT_PER_METER;
+
+    *visibility = Visibility::Visible;
+    sprite.custom_size = Some(Vec2::new(BEACH_WIDTH, shift.abs()));
+    sprite.color = if shift > 0. { WATER_COLOR } else { SAND_COLOR };
+    transform.translation.y = FISHING_ROOM_Y + SHORELINE_Y - shift / 2.;
+}
@@ -0,0 +1,87 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::fishing_view::*;
+use crate::gameday::*;
+use crate::weather::*;
+
+// Clarity per game hour lost while runoff clouds the water, and regained as
+// the silt settles afterwards
+const CLOUDING_RATE: f32 = 0.3;
+const SETTLING_RATE: f32 = 0.05;
+
+// Fraction of their clear water sight range fish keep in the murkiest water
+const MURKY_SIGHT: f32 = 0.4;
+
+// How clear the water is at each fishing spot, 0 is mud and 1 is glass
+#[derive(Resource)]
+pub struct WaterClarity {
+    clarity: HashMap<FishingLocal, f32>,
+}
+
+impl Default for WaterClarity {
+    fn default() -> Self {
+        let mut clarity = HashMap::new();
+        for local in [FishingLocal::Pond1, FishingLocal::Pond2, FishingLocal::Ocean] {
+            clarity.insert(local.clone(), max_clarity(&local));
+        }
+        Self { clarity }
+    }
+}
+
+impl WaterClarity {
+    pub fn get(&self, local: &FishingLocal) -> f32 {
+        *self.clarity.get(local).unwrap_or(&1.)
+    }
+
+    // Scale for how far fish can see a lure
+    pub fn sight_factor(&self, local: &FishingLocal) -> f32 {
+        MURKY_SIGHT + (1. - MURKY_SIGHT) * self.get(local)
+    }
+
+    // How much a fish notices the line, colour stops mattering in murky water
+    pub fn line_seen(&self, local: &FishingLocal, line_type: &FishingLineType) -> f32 {
+        line_type.visibility * self.get(local)
+    }
+}
+
+// Clearest each spot gets in settled weather
+fn max_clarity(local: &FishingLocal) -> f32 {
+    match local {
+        FishingLocal::Pond1 => 0.8,
+        FishingLocal::Pond2 => 0.7,
+        FishingLocal::Ocean => 0.95,
+    }
+}
+
+// Rain and storms wash silt in, clarity can't rise above this while they last
+fn weather_clarity_cap(weather: &Weather) -> f32 {
+    match weather {
+        Weather::Rainy => 0.45,
+        Weather::Thunderstorm => 0.2,
+        Weather::Snow => 0.6,
+        _ => 1.,
+    }
+}
+
+pub fn update_water_clarity(
+    timer: Res<GameDayTimer>,
+    weather_state: Res<WeatherState>,
+    mut water: ResMut<WaterClarity>,
+) {
+    let dt = timer.delta_hours();
+
+    for local in [FishingLocal::Pond1, FishingLocal::Pond2, FishingLocal::Ocean] {
+        let weather = weather_state.weather_by_region.get(&local.region()).unwrap_or(&Weather::Sunny);
+        let target = max_clarity(&local).min(weather_clarity_cap(weather));
+        let current = water.get(&local);
+
+        let next = if current > target {
+            (current - CLOUDING_RATE * dt).max(target)
+        } else {
+            (current + SETTLING_RATE * dt).min(target)
+        };
+
+        water.clarity.insert(local, next);
+    }
+}
@@ -0,0 +1,112 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+use std::f32::consts::PI;
+
+use crate::fishing_view::*;
+use crate::gameday::*;
+use crate::weather::*;
+
+// How quickly the water follows the air, per game hour. Ponds warm up and cool
+// down much faster than the sea.
+const POND_RESPONSE: f32 = 0.5;
+const OCEAN_RESPONSE: f32 = 0.1;
+
+// Half the gap between the warmest and coolest water over a day
+const POND_DAILY_SWING: f32 = 3.;
+const OCEAN_DAILY_SWING: f32 = 1.;
+const WARMEST_HOUR: f32 = 15.;
+
+// Ponds cool a little with depth but the bottom never drops below 4C
+const POND_DEPTH_COOLING: f32 = 0.1;
+const POND_BOTTOM_MIN: f32 = 4.;
+
+// Below the thermocline the ocean is cold all year round
+const THERMOCLINE_DEPTH: f32 = 40.;
+const THERMOCLINE_THICKNESS: f32 = 30.;
+const DEEP_OCEAN_TEMP: f32 = 8.;
+
+// Surface water temperature at each fishing spot in degrees C
+#[derive(Resource)]
+pub struct WaterTemperature {
+    surface: HashMap<FishingLocal, f32>,
+}
+
+impl Default for WaterTemperature {
+    fn default() -> Self {
+        let mut surface = HashMap::new();
+        surface.insert(FishingLocal::Pond1, 16.);
+        surface.insert(FishingLocal::Pond2, 16.);
+        surface.insert(FishingLocal::Ocean, 18.);
+        Self { surface }
+    }
+}
+
+impl WaterTemperature {
+    pub fn surface(&self, local: &FishingLocal) -> f32 {
+        *self.surface.get(local).unwrap_or(&16.)
+    }
+
+    // Temperature depth metres below the surface
+    pub fn at(&self, local: &FishingLocal, depth: f32) -> f32 {
+        let surface = self.surface(local);
+        let depth = depth.max(0.);
+
+        if *local == FishingLocal::Ocean {
+            // Smooth drop through the thermocline
+            let t = ((depth - (THERMOCLINE_DEPTH - THERMOCLINE_THICKNESS / 2.)) / THERMOCLINE_THICKNESS).clamp(0., 1.);
+            let blend = t * t * (3. - 2. * t);
+            surface + (DEEP_OCEAN_TEMP - surface) * blend
+        } else {
+            (surface - POND_DEPTH_COOLING * depth).max(POND_BOTTOM_MIN.min(surface))
+        }
+    }
+}
+
+fn season_temp(local: &FishingLocal, season: Season) -> f32 {
+    match (local, season) {
+        (FishingLocal::Ocean, Season::Spring) => 18.,
+        (FishingLocal::Ocean, Season::Summer) => 24.,
+        (FishingLocal::Ocean, Season::Autumn) => 20.,
+        (FishingLocal::Ocean, Season::Winter) => 14.,
+        (_, Season::Spring) => 16.,
+        (_, Season::Summer) => 25.,
+        (_, Season::Autumn) => 14.,
+        (_, Season::Winter) => 4.,
+    }
+}
+
+fn weather_offset(weather: &Weather) -> f32 {
+    match weather {
+        Weather::Sunny => 1.5,
+        Weather::Cloudy => 0.,
+        Weather::Rainy => -1.5,
+        Weather::Thunderstorm => -2.,
+        Weather::Fog => -0.5,
+        Weather::Snow => -3.,
+    }
+}
+
+pub fn update_water_temperature(
+    timer: Res<GameDayTimer>,
+    weather_state: Res<WeatherState>,
+    mut water: ResMut<WaterTemperature>,
+) {
+    let dt = timer.delta_hours();
+    let daily = f32::cos(2. * PI * (timer.hours() - WARMEST_HOUR) / 24.);
+
+    for local in [FishingLocal::Pond1, FishingLocal::Pond2, FishingLocal::Ocean] {
+        let weather = weather_state.weather_by_region.get(&local.region()).unwrap_or(&Weather::Sunny);
+
+        let (swing, response) = if local == FishingLocal::Ocean {
+            (OCEAN_DAILY_SWING, OCEAN_RESPONSE)
+        } else {
+            (POND_DAILY_SWING, POND_RESPONSE)
+        };
+
+        let target = season_temp(&local, timer.season()) + swing * daily + weather_offset(weather);
+        let current = water.surface(&local);
+        let next = current + (target - current) * (1. - f32::exp(-response * dt));
+
+        water.surface.insert(local, next);
+    }
+}
@@ -2,9 +2,21 @@ use bevy::{prelude::*, utils::HashMap};
 use rand::seq::SliceRandom;
 use rand::prelude::*;
 
-use crate::{interface::CurrentInterface, player::Player, window::{WIN_H, WIN_W}};
+use crate::{gameday::{day_tint_color, GameDayTimer, Season}, interface::CurrentInterface, player::Player, window::{WIN_H, WIN_W}};
 
 const WEATHER_UPDATE_PERIOD: f32 = 20.;
+const WEATHER_TINT_FADE_RATE: f32 = 0.5;
+const WIND_UPDATE_PERIOD: f32 = 5.;
+// How quickly the wind settles on a new target, per second
+const WIND_EASE_RATE: f32 = 0.2;
+// How far the wind can veer each update
+const WIND_VEER: f32 = std::f32::consts::PI / 4.;
+// How much wind pushes falling rain sideways
+const RAIN_WIND_FACTOR: f32 = 20.;
+// Pressure change per game hour that counts as rising or falling
+const PRESSURE_TREND_THRESHOLD: f32 = 1.;
+// How quickly the reported trend follows the actual change, per game hour
+const PRESSURE_TREND_SMOOTHING: f32 = 2.;
 
 #[derive(Event)]
 pub struct RegionChangedEvent(pub Region);
@@ -23,9 +35,36 @@ pub enum Region{
 #[derive(Resource)]
 pub struct WeatherState {
     pub weather_by_region: HashMap<Region, Weather>,
+    // Weather each region moves to at the next change, rolled a period early
+    // so the barometer can see it coming
+    pub forecast_by_region: HashMap<Region, Weather>,
     pub change_timer: Timer,
 }
 
+// Air pressure per region in hPa, heads towards the forecast weather
+#[derive(Resource)]
+pub struct PressureState {
+    pub pressure_by_region: HashMap<Region, f32>,
+    // hPa per game hour
+    pub trend_by_region: HashMap<Region, f32>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PressureTrend {
+    Rising,
+    Steady,
+    Falling,
+}
+
+
+// Wind velocity per region. Eases towards a target picked from the weather
+// so gusts pick up as a storm rolls in.
+#[derive(Resource)]
+pub struct WindState {
+    pub wind_by_region: HashMap<Region, Vec2>,
+    pub target_by_region: HashMap<Region, Vec2>,
+    pub change_timer: Timer,
+}
 
 #[derive(States, Debug, Clone, PartialEq, Eq, Hash, Copy, Default)]
 pub enum Weather {
@@ -34,11 +73,14 @@ pub enum Weather {
     Rainy,
     Cloudy,
     Thunderstorm,
+    Fog,
+    Snow,
 }
 
 #[derive(Component)]
 pub struct RainParticle{
     velocity: Vec2,
+    sway: f32,
 }
 
 #[derive(Component)]
@@ -52,19 +94,109 @@ impl Default for WeatherState {
             weather_by_region.insert(region.clone(), Weather::Sunny);
         }
         Self{
+            forecast_by_region: weather_by_region.clone(),
             weather_by_region,
             change_timer: Timer::from_seconds(WEATHER_UPDATE_PERIOD, TimerMode::Repeating),
         }
     }
 }
 
+impl Default for PressureState {
+    fn default() -> Self {
+        let mut pressure_by_region = HashMap::new();
+        let mut trend_by_region = HashMap::new();
+        for region in [Region::West, Region::Central, Region::Shore].iter() {
+            pressure_by_region.insert(*region, Weather::Sunny.pressure());
+            trend_by_region.insert(*region, 0.);
+        }
+        Self{
+            pressure_by_region,
+            trend_by_region,
+        }
+    }
+}
+
+impl PressureState {
+    pub fn get(&self, region: &Region) -> f32 {
+        *self.pressure_by_region.get(region).unwrap_or(&Weather::Sunny.pressure())
+    }
+
+    pub fn trend(&self, region: &Region) -> PressureTrend {
+        let trend = *self.trend_by_region.get(region).unwrap_or(&0.);
+        if trend <= -PRESSURE_TREND_THRESHOLD {
+            PressureTrend::Falling
+        } else if trend >= PRESSURE_TREND_THRESHOLD {
+            PressureTrend::Rising
+        } else {
+            PressureTrend::Steady
+        }
+    }
+}
+
+impl Default for WindState {
+    fn default() -> Self {
+        let mut wind_by_region = HashMap::new();
+        for region in [Region::West, Region::Central, Region::Shore].iter() {
+            wind_by_region.insert(*region, Vec2::new(Weather::Sunny.wind_speed(), 0.));
+        }
+        Self{
+            target_by_region: wind_by_region.clone(),
+            wind_by_region,
+            change_timer: Timer::from_seconds(WIND_UPDATE_PERIOD, TimerMode::Repeating),
+        }
+    }
+}
+
+impl WindState {
+    pub fn get(&self, region: &Region) -> Vec2 {
+        *self.wind_by_region.get(region).unwrap_or(&Vec2::ZERO)
+    }
+}
+
 impl Weather{
-    fn get_next_states(&self) -> Vec<Weather> {
+    // Typical wind speed for the weather
+    pub fn wind_speed(&self) -> f32 {
+        match self {
+            Weather::Sunny => 2.,
+            Weather::Cloudy => 4.,
+            Weather::Rainy => 6.,
+            Weather::Thunderstorm => 12.,
+            Weather::Fog => 0.5,
+            Weather::Snow => 5.,
+        }
+    }
+
+    // Typical air pressure in hPa, low ahead of storms and high in fair weather
+    pub fn pressure(&self) -> f32 {
         match self {
-            Weather::Sunny => vec![Weather::Cloudy, Weather::Sunny],
-            Weather::Cloudy => vec![Weather::Rainy, Weather::Thunderstorm, Weather::Sunny, Weather::Cloudy],
-            Weather::Rainy => vec![Weather::Cloudy, Weather::Thunderstorm, Weather::Rainy],
-            Weather::Thunderstorm => vec![Weather::Cloudy, Weather::Rainy, Weather::Thunderstorm],
+            Weather::Sunny => 1022.,
+            Weather::Cloudy => 1013.,
+            Weather::Rainy => 1005.,
+            Weather::Thunderstorm => 995.,
+            Weather::Fog => 1018.,
+            Weather::Snow => 1008.,
+        }
+    }
+
+    // Snow takes the place of rain and storms in cold seasons
+    fn get_next_states(&self, season: Season) -> Vec<Weather> {
+        if season.is_cold() {
+            match self {
+                Weather::Sunny => vec![Weather::Cloudy, Weather::Sunny, Weather::Fog],
+                Weather::Cloudy => vec![Weather::Snow, Weather::Sunny, Weather::Cloudy, Weather::Fog],
+                Weather::Rainy | Weather::Thunderstorm => vec![Weather::Cloudy, Weather::Snow],
+                Weather::Fog => vec![Weather::Sunny, Weather::Cloudy, Weather::Fog],
+                Weather::Snow => vec![Weather::Cloudy, Weather::Snow, Weather::Fog],
+            }
+        } else {
+            match self {
+                Weather::Sunny => vec![Weather::Cloudy, Weather::Sunny, Weather::Fog],
+                Weather::Cloudy => vec![Weather::Rainy, Weather::Thunderstorm, Weather::Sunny, Weather::Cloudy, Weather::Fog],
+                Weather::Rainy => vec![Weather::Cloudy, Weather::Thunderstorm, Weather::Rainy, Weather::Fog],
+                Weather::Thunderstorm => vec![Weather::Cloudy, Weather::Rainy, Weather::Thunderstorm],
+                Weather::Fog => vec![Weather::Sunny, Weather::Cloudy, Weather::Fog],
+                Weather::Snow => vec![Weather::Cloudy, Weather::Rainy],
+            }
         }
     }
 }
@@ -72,7 +204,7 @@ impl Weather{
 
 
 pub fn update_weather(
-    time: Res<Time>, 
+    time: Res<GameDayTimer>, 
     mut weather_state: ResMut<WeatherState>,
     mut next_weather: ResMut<NextState<Weather>>,
     current_region: Res<State<Region>>,
@@ -83,9 +215,13 @@ pub fn update_weather(
     if weather_state.change_timer.tick(time.delta()).just_finished() {
         // Choose a random weather state from the next possible states.
         let mut rng = rand::thread_rng();
-        for (_region, current_weather) in weather_state.weather_by_region.iter_mut(){
-            let next_states = current_weather.get_next_states();
-            *current_weather = *next_states.choose(&mut rng).unwrap();
+        let season = time.season();
+        let weather_state = weather_state.as_mut();
+        for (region, current_weather) in weather_state.weather_by_region.iter_mut(){
+            let forecast = weather_state.forecast_by_region.entry(*region).or_insert(*current_weather);
+            *current_weather = *forecast;
+            let next_states = current_weather.get_next_states(season);
+            *forecast = *next_states.choose(&mut rng).unwrap();
         }
         
         if let Some(current_weather) = weather_state.weather_by_region.get(current_region.get()){
@@ -94,15 +230,72 @@ pub fn update_weather(
     }
 }
 
+// Pressure slides towards the forecast weather so that it has arrived by the
+// time the weather changes, falling ahead of a storm and rising after it
+pub fn update_pressure(
+    time: Res<GameDayTimer>,
+    weather_state: Res<WeatherState>,
+    mut pressure_state: ResMut<PressureState>,
+) {
+    let dt = time.delta_hours();
+    if dt <= 0. {
+        return;
+    }
+
+    let remaining = weather_state.change_timer.remaining_secs().max(time.delta().as_secs_f32());
+    let t = (time.delta().as_secs_f32() / remaining).min(1.);
+    let pressure_state = pressure_state.as_mut();
+
+    for (region, pressure) in pressure_state.pressure_by_region.iter_mut() {
+        let forecast = weather_state.forecast_by_region.get(region).unwrap_or(&Weather::Sunny);
+        let change = (forecast.pressure() - *pressure) * t;
+        *pressure += change;
+
+        let trend = pressure_state.trend_by_region.entry(*region).or_insert(0.);
+        *trend += (change / dt - *trend) * (PRESSURE_TREND_SMOOTHING * dt).min(1.);
+    }
+}
+
+pub fn update_wind(
+    time: Res<GameDayTimer>,
+    weather_state: Res<WeatherState>,
+    mut wind_state: ResMut<WindState>,
+) {
+    let wind_state = wind_state.as_mut();
+
+    if wind_state.change_timer.tick(time.delta()).just_finished() {
+        let mut rng = rand::thread_rng();
+        for (region, target) in wind_state.target_by_region.iter_mut() {
+            let weather = weather_state.weather_by_region.get(region).unwrap_or(&Weather::Sunny);
+            let speed = weather.wind_speed() * rng.gen_range(0.5..1.5);
+            let heading = target.to_angle() + rng.gen_range(-WIND_VEER..WIND_VEER);
+            *target = speed * Vec2::from_angle(heading);
+        }
+    }
+
+    let t = (WIND_EASE_RATE * time.delta().as_secs_f32()).min(1.);
+    for (region, wind) in wind_state.wind_by_region.iter_mut() {
+        let target = *wind_state.target_by_region.get(region).unwrap_or(&Vec2::ZERO);
+        *wind = wind.lerp(target, t);
+    }
+}
+
+// Also true for snow, which shares the particle system
 pub fn run_if_raining( weather_state: Res<WeatherState>, current_region: Res<State<Region>>) -> bool{
     let current_weather = weather_state.weather_by_region.get(current_region.get()).unwrap_or(&Weather::Sunny);
-    return *current_weather == Weather::Rainy || *current_weather == Weather::Thunderstorm;
+    return *current_weather == Weather::Rainy || *current_weather == Weather::Thunderstorm || *current_weather == Weather::Snow;
 }
 pub fn rain_particle_system(
     mut commands: Commands,
     mut query: Query<(Entity, &RainParticle, &mut Transform, &mut Sprite)>,
     time: Res<Time>,
+    weather_state: Res<WeatherState>,
+    wind_state: Res<WindState>,
+    current_region: Res<State<Region>>,
 ) {
+    let current_weather = weather_state.weather_by_region.get(current_region.get()).unwrap_or(&Weather::Sunny);
+    let snowing = *current_weather == Weather::Snow;
+    let wind = wind_state.get(current_region.get());
 
     
     
@@ -123,14 +316,18 @@ pub fn rain_particle_system(
         }
 
         
-        let wind:  f32 = rng.gen();
-        transform.translation.x += wind;
+        // Wind slants the rain, with a little jitter so it isn't uniform
+        let jitter: f32 = rng.gen();
+        transform.translation.x += wind.x * RAIN_WIND_FACTOR * time.delta_seconds() + jitter;
+
+        // Snowflakes drift side to side as they fall
+        transform.translation.x += particle.sway * f32::sin(time.elapsed_seconds() * 2. + transform.translation.y * 0.01);
 
     }
 
     // Spawn new particles if needed
     if query.iter().count() < 1000 { // Adjust this number as needed
-        spawn_rain_particle(&mut commands,  window_width, window_height);
+        spawn_rain_particle(&mut commands,  window_width, window_height, snowing);
     }
 }
 
@@ -138,23 +335,42 @@ fn spawn_rain_particle(
     commands: &mut Commands,
     window_width: f32,
     window_height: f32,
+    snow: bool,
 ) {
     let x = random::<f32>() * window_width - window_width / 2.0;
     let y = window_height / 2.0 + random::<f32>() * 100.0;
 
+    let (color, size, particle) = if snow {
+        (
+            Color::srgba(0.95, 0.95, 1.0, 0.9),
+            8.0,
+            RainParticle {
+                velocity: Vec2::new(0.0, -150.0 - random::<f32>() * 60.0),
+                sway: 0.5 + random::<f32>(),
+            },
+        )
+    } else {
+        (
+            Color::srgba(0.7, 0.7, 0.9, 0.8),
+            5.0,
+            RainParticle {
+                velocity: Vec2::new(0.0, -750.0 - random::<f32>() * 100.0), // Adjust speed as needed
+                sway: 0.,
+            },
+        )
+    };
+
     commands.spawn((
         SpriteBundle {
             sprite: Sprite{
-                color: Color::srgba(0.7, 0.7, 0.9, 0.8),
-                custom_size: Some(Vec2::new(5.0, 5.0)),
+                color,
+                custom_size: Some(Vec2::new(size, size)),
                 ..default()
             }, // Adjust size as needed
             transform: Transform::from_translation(Vec3::new(x, y, 999.0)),
             ..Default::default()
         },
-        RainParticle {
-            velocity: Vec2::new(0.0, -750.0 - random::<f32>() * 100.0), // Adjust speed as needed
-        },
+        particle,
     ));
 }
 
@@ -167,6 +383,8 @@ pub fn despawn_rain_particles(
     }
 }
 pub fn update_weather_tint(
+    time: Res<Time>,
+    timer: Res<GameDayTimer>,
     weather_state: Res<WeatherState>, 
     current_interface: Res<State<CurrentInterface>>,
     current_region: Res<State<Region>>,
@@ -177,20 +395,24 @@ pub fn update_weather_tint(
             sprite.color = Color::srgba(0.5, 0.5, 0.5, 0.0);
         } else {
             let current_weather = weather_state.weather_by_region.get(current_region.get()).unwrap_or(&Weather::Sunny);
-            match current_weather {
-                Weather::Cloudy => { 
-                    sprite.color = Color::srgba(0.4, 0.4, 0.4, 0.25);
-                },
-                Weather::Rainy => { 
-                    sprite.color = Color::srgba(0.4, 0.4, 0.4, 0.5);
-                },
-                Weather::Thunderstorm => { 
-                    sprite.color = Color::srgba(0.4, 0.4, 0.4, 0.6);
-                },
-                _ => {
-                    sprite.color = Color::srgba(0.5, 0.5, 0.5, 0.0);
-                }
-            }
+            let weather_color = match current_weather {
+                Weather::Cloudy => Srgba::new(0.4, 0.4, 0.4, 0.25),
+                Weather::Rainy => Srgba::new(0.4, 0.4, 0.4, 0.5),
+                Weather::Thunderstorm => Srgba::new(0.4, 0.4, 0.4, 0.6),
+                Weather::Fog => Srgba::new(0.85, 0.85, 0.85, 0.55),
+                Weather::Snow => Srgba::new(0.8, 0.85, 0.95, 0.3),
+                _ => Srgba::new(0.5, 0.5, 0.5, 0.0),
+            };
+
+            // Pull the cloud colour towards the day tint so overcast nights
+            // stay blue instead of washing out grey
+            let day_color = day_tint_color(timer.hours());
+            let target = weather_color.mix(&day_color.with_alpha(weather_color.alpha), day_color.alpha);
+
+            // Ease towards the target so weather changes fade in
+            let current = sprite.color.to_srgba();
+            let t = (WEATHER_TINT_FADE_RATE * time.delta_seconds()).min(1.);
+            sprite.color = current.mix(&target, t).into();
         }
     }
 }